@@ -0,0 +1,239 @@
+//! **everywhere‑actor** – typed actors on any `R: Runtime`.
+//!
+//! * an [`Actor`] owns its state and handles one message at a time;
+//! * [`Addr`] is the cheap, clone‑able handle: [`send`](Addr::send) fires
+//!   and forgets, [`ask`](Addr::ask) waits for the reply over a oneshot;
+//! * mailboxes are **bounded** – a full mailbox makes `send` wait;
+//! * a [`Supervisor`] rebuilds a panicked actor after an
+//!   [`everywhere_timer::Timer`] back‑off.
+//!
+//! ```no_run
+//! use everywhere_actor::{self as actor, Actor};
+//!
+//! struct Counter(u64);
+//!
+//! impl Actor for Counter {
+//!     type Msg = u64;
+//!     type Reply = u64;
+//!     async fn handle(&mut self, by: u64) -> u64 { self.0 += by; self.0 }
+//! }
+//!
+//! # async fn demo() {
+//! let mut addr = actor::spawn(Counter(0), 16);
+//! addr.send(1).await.unwrap();
+//! assert_eq!(addr.ask(2).await.unwrap(), 3);
+//! # }
+//! ```
+
+#![allow(async_fn_in_trait)]
+
+use core::{fmt, future::Future, marker::PhantomData, panic::AssertUnwindSafe, time::Duration};
+use everywhere_runtime::{
+    prelude::Runtime, BoundedSendMessage, MaybeSend, OneshotSend, Rt, SendError,
+    TrySendError,
+};
+use everywhere_timer::Timer;
+use futures_util::{FutureExt, StreamExt};
+
+/*──────────── actor contract ───────────*/
+
+/// State plus behaviour; runs on its own task and sees one message at a
+/// time, so `&mut self` needs no locking.
+pub trait Actor: Sized + MaybeSend + 'static {
+    type Msg: MaybeSend + 'static;
+    /// What [`Addr::ask`] resolves to; `send` discards it.
+    type Reply: MaybeSend + 'static;
+
+    fn handle(&mut self, msg: Self::Msg) -> impl Future<Output = Self::Reply> + MaybeSend;
+
+    /// Runs before the first message – and again after every restart.
+    fn started(&mut self) -> impl Future<Output = ()> + MaybeSend { async {} }
+
+    /// Runs once every [`Addr`] is gone and the mailbox is drained. A
+    /// panicked instance is dropped without it.
+    fn stopped(&mut self) -> impl Future<Output = ()> + MaybeSend { async {} }
+}
+
+/*──────────── mailbox ───────────*/
+
+enum Envelope<A: Actor, R: Runtime> {
+    Tell(A::Msg),
+    Ask(A::Msg, R::OneshotSender<A::Reply>),
+}
+
+/// Handle to a running actor; clone one per producer. The actor stops
+/// once the last clone drops.
+pub struct Addr<A: Actor, R: Runtime = Rt> {
+    tx: R::BoundedSender<Envelope<A, R>>,
+}
+
+impl<A: Actor, R: Runtime> Clone for Addr<A, R> {
+    fn clone(&self) -> Self { Self { tx: self.tx.clone() } }
+}
+
+impl<A: Actor, R: Runtime> fmt::Debug for Addr<A, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("Addr(..)") }
+}
+
+impl<A: Actor, R: Runtime> Addr<A, R> {
+    /// Queue `msg`, waiting while the mailbox is full.
+    pub async fn send(&mut self, msg: A::Msg) -> Result<(), SendError<A::Msg>> {
+        self.tx.send(Envelope::Tell(msg)).await.map_err(|e| SendError(e.0.into_msg()))
+    }
+
+    /// Queue `msg` only if there is room right now.
+    pub fn try_send(&mut self, msg: A::Msg) -> Result<(), TrySendError<A::Msg>> {
+        self.tx.try_send(Envelope::Tell(msg)).map_err(|e| match e {
+            TrySendError::Full(e)   => TrySendError::Full(e.into_msg()),
+            TrySendError::Closed(e) => TrySendError::Closed(e.into_msg()),
+        })
+    }
+
+    /// Queue `msg` and wait for the actor's reply.
+    pub async fn ask(&mut self, msg: A::Msg) -> Result<A::Reply, AskError<A::Msg>> {
+        let (reply, rx) = R::oneshot();
+        self.tx.send(Envelope::Ask(msg, reply)).await
+            .map_err(|e| AskError::Closed(e.0.into_msg()))?;
+        rx.await.map_err(|_| AskError::NoReply)
+    }
+}
+
+impl<A: Actor, R: Runtime> Envelope<A, R> {
+    fn into_msg(self) -> A::Msg {
+        match self { Self::Tell(m) | Self::Ask(m, _) => m }
+    }
+}
+
+/// Why [`Addr::ask`] got no answer.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AskError<M> {
+    /// The actor is gone; the message is handed back.
+    Closed(M),
+    /// The actor took the message but panicked before replying.
+    NoReply,
+}
+
+impl<M> fmt::Debug for AskError<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed(_) => f.write_str("Closed(..)"),
+            Self::NoReply   => f.write_str("NoReply"),
+        }
+    }
+}
+
+impl<M> fmt::Display for AskError<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed(_) => f.write_str("actor stopped"),
+            Self::NoReply   => f.write_str("actor dropped the request"),
+        }
+    }
+}
+
+impl<M> std::error::Error for AskError<M> {}
+
+/*──────────── spawning ───────────*/
+
+/// Run `actor` on the crate‑level [`Rt`] with room for `cap` queued
+/// messages. A panic stops it for good – see [`Supervisor`].
+pub fn spawn<A: Actor>(actor: A, cap: usize) -> Addr<A> { spawn_with::<Rt, A>(actor, cap) }
+
+/// [`spawn`] on any `R: Runtime`.
+pub fn spawn_with<R: Runtime, A: Actor>(actor: A, cap: usize) -> Addr<A, R> {
+    let mut actor = Some(actor);
+    Supervisor::<A, R>::new_with(move || actor.take().expect("restarts are off"), |_| Duration::ZERO)
+        .max_restarts(0)
+        .spawn(cap)
+}
+
+/*──────────── supervision ───────────*/
+
+#[cfg(not(target_arch = "wasm32"))]
+type Factory<A> = dyn FnMut() -> A + Send + 'static;
+#[cfg(target_arch = "wasm32")]
+type Factory<A> = dyn FnMut() -> A + 'static;
+
+/// Restarts a panicked actor with a fresh instance from `factory`, after
+/// the [`Timer`] back‑off `calc(n)` for the `n`‑th consecutive restart.
+///
+/// Queued messages survive a restart; an `ask` whose handler panicked
+/// gets [`AskError::NoReply`]. The back‑off resets once a restarted
+/// actor handles a message cleanly.
+///
+/// Supervision relies on unwinding: `wasm32-unknown-unknown` builds abort
+/// on panic, so in the browser a panicking actor takes the instance down
+/// and is never restarted.
+pub struct Supervisor<A: Actor, R: Runtime = Rt> {
+    factory: Box<Factory<A>>,
+    timer: Timer<R>,
+    max_restarts: usize,
+    _rt: PhantomData<fn() -> R>,
+}
+
+impl<A: Actor> Supervisor<A> {
+    /// Supervise on the crate‑level runtime.
+    pub fn new<C, F>(factory: C, calc: F) -> Self
+    where
+        C: FnMut() -> A + MaybeSend + 'static,
+        F: Fn(usize) -> Duration + Send + Sync + 'static,
+    {
+        Self::new_with(factory, calc)
+    }
+}
+
+impl<A: Actor, R: Runtime> Supervisor<A, R> {
+    /// Supervise on any `R: Runtime`.
+    pub fn new_with<C, F>(factory: C, calc: F) -> Self
+    where
+        C: FnMut() -> A + MaybeSend + 'static,
+        F: Fn(usize) -> Duration + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(factory),
+            timer: Timer::new_with(|| {}, calc),
+            max_restarts: usize::MAX,
+            _rt: PhantomData,
+        }
+    }
+
+    /// Give up after `n` consecutive restarts (default: never).
+    pub fn max_restarts(mut self, n: usize) -> Self {
+        self.max_restarts = n;
+        self
+    }
+
+    /// Start the first instance with room for `cap` queued messages.
+    pub fn spawn(self, cap: usize) -> Addr<A, R> {
+        let (tx, rx) = R::channel_bounded(cap);
+        drop(R::spawn(self.run(rx)));
+        Addr { tx }
+    }
+
+    async fn run(mut self, mut mailbox: R::BoundedReceiver<Envelope<A, R>>) {
+        let mut restarted = false;
+        loop {
+            let mut actor = (self.factory)();
+            let lived = AssertUnwindSafe(async {
+                actor.started().await;
+                while let Some(env) = mailbox.next().await {
+                    match env {
+                        Envelope::Tell(msg) => { actor.handle(msg).await; }
+                        Envelope::Ask(msg, reply) => { let _ = reply.send(actor.handle(msg).await); }
+                    }
+                    if restarted {
+                        self.timer.reset();
+                        restarted = false;
+                    }
+                }
+                actor.stopped().await;
+            })
+            .catch_unwind()
+            .await;
+
+            if lived.is_ok() || self.timer.tries() >= self.max_restarts { return; }
+            let _ = self.timer.schedule_timeout().await;
+            restarted = true;
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! `Addr` messaging, bounded mailboxes and lifecycle hooks.
+//!
+//! Most cases run on [`MockRt`]: its tasks only move when the test drives
+//! them, so mailbox contents are exact.
+
+use everywhere_actor::{self as actor, Actor, AskError};
+use everywhere_runtime::{MockRt, TrySendError};
+use everywhere_test::cross_test;
+use std::sync::{Arc, Mutex};
+
+/// Adds to a running total; logs its lifecycle.
+struct Counter {
+    total: u64,
+    log: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl Actor for Counter {
+    type Msg = u64;
+    type Reply = u64;
+
+    async fn handle(&mut self, by: u64) -> u64 {
+        self.total += by;
+        self.total
+    }
+    async fn started(&mut self) { self.log.lock().unwrap().push("started"); }
+    async fn stopped(&mut self) { self.log.lock().unwrap().push("stopped"); }
+}
+
+fn counter() -> (Counter, Arc<Mutex<Vec<&'static str>>>) {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    (Counter { total: 0, log: log.clone() }, log)
+}
+
+/*──────────── send / ask ───────────*/
+
+#[cross_test]
+async fn ask_sees_earlier_sends() {
+    let (c, _) = counter();
+    let mut addr = actor::spawn(c, 8);
+
+    addr.send(1).await.unwrap();
+    addr.clone().send(2).await.unwrap();
+    assert_eq!(addr.ask(3).await.unwrap(), 6);
+}
+
+/*──────────── bounded mailbox ───────────*/
+
+#[cross_test]
+async fn full_mailbox_rejects_try_send() {
+    let (c, _) = counter();
+    let mut addr = actor::spawn_with::<MockRt, _>(c, 2);
+
+    addr.try_send(1).unwrap();
+    addr.try_send(2).unwrap();
+    assert!(matches!(addr.try_send(3), Err(TrySendError::Full(3))));
+
+    MockRt::run_until_stalled();            // actor drains the mailbox
+    addr.try_send(3).unwrap();
+    assert_eq!(MockRt::block_on(addr.ask(0)).unwrap(), 6);
+}
+
+/*──────────── lifecycle ───────────*/
+
+#[cross_test]
+async fn hooks_bracket_the_mailbox() {
+    let (c, log) = counter();
+    let mut addr = actor::spawn_with::<MockRt, _>(c, 4);
+    MockRt::run_until_stalled();
+    assert_eq!(*log.lock().unwrap(), ["started"]);
+
+    addr.try_send(5).unwrap();
+    drop(addr);                              // queued message still runs
+    MockRt::run_until_stalled();
+    assert_eq!(*log.lock().unwrap(), ["started", "stopped"]);
+}
+
+/// Without a supervisor a panic ends the actor; messages come back.
+#[cross_test]
+async fn unsupervised_panic_closes_mailbox() {
+    struct Fragile;
+    impl Actor for Fragile {
+        type Msg = bool;
+        type Reply = ();
+        async fn handle(&mut self, boom: bool) { assert!(!boom, "boom"); }
+    }
+
+    let mut addr = actor::spawn_with::<MockRt, _>(Fragile, 4);
+    assert_eq!(MockRt::block_on(addr.ask(true)), Err(AskError::NoReply));
+    assert_eq!(MockRt::block_on(addr.ask(false)), Err(AskError::Closed(false)));
+}
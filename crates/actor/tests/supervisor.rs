@@ -0,0 +1,76 @@
+//! Supervised restarts on [`MockRt`]: back‑off delays are exact.
+
+use core::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+use everywhere_actor::{Actor, AskError, Supervisor};
+use everywhere_runtime::{time, MockRt};
+use everywhere_test::cross_test;
+use std::sync::Arc;
+
+const MS: Duration = Duration::from_millis(1);
+
+/// Echoes its message; panics on `0`.
+struct Picky;
+
+impl Actor for Picky {
+    type Msg = u32;
+    type Reply = u32;
+    async fn handle(&mut self, n: u32) -> u32 {
+        assert_ne!(n, 0, "zero");
+        n
+    }
+}
+
+/// Supervisor on `MockRt` with a `10 ms · n` back‑off; counts builds.
+fn supervised() -> (Arc<AtomicUsize>, Supervisor<Picky, MockRt>) {
+    let built = Arc::new(AtomicUsize::new(0));
+    let b = built.clone();
+    let sup = Supervisor::new_with(move || { b.fetch_add(1, Ordering::Relaxed); Picky },
+                                   |n| 10 * n as u32 * MS);
+    (built, sup)
+}
+
+#[cross_test]
+async fn restarts_after_backoff() {
+    let (built, sup) = supervised();
+    let mut addr = sup.spawn(4);
+    let start = time::now_with::<MockRt>();
+
+    assert_eq!(MockRt::block_on(addr.ask(0)), Err(AskError::NoReply));
+    assert_eq!(MockRt::block_on(addr.ask(7)), Ok(7));
+    assert_eq!(built.load(Ordering::Relaxed), 2);
+    assert_eq!(time::now_with::<MockRt>() - start, 10 * MS);
+}
+
+#[cross_test]
+async fn backoff_grows_then_resets() {
+    let (_, sup) = supervised();
+    let mut addr = sup.spawn(4);
+    let start = time::now_with::<MockRt>();
+
+    addr.try_send(0).unwrap();
+    addr.try_send(0).unwrap();
+    assert_eq!(MockRt::block_on(addr.ask(1)), Ok(1));
+    assert_eq!(time::now_with::<MockRt>() - start, 30 * MS);   // 10 + 20
+
+    /* a clean message in between starts the count over */
+    let again = time::now_with::<MockRt>();
+    addr.try_send(0).unwrap();
+    assert_eq!(MockRt::block_on(addr.ask(1)), Ok(1));
+    assert_eq!(time::now_with::<MockRt>() - again, 10 * MS);
+}
+
+#[cross_test]
+async fn gives_up_after_max_restarts() {
+    let (built, sup) = supervised();
+    let mut addr = sup.max_restarts(1).spawn(4);
+
+    addr.try_send(0).unwrap();
+    addr.try_send(0).unwrap();
+    MockRt::advance(100 * MS);
+
+    assert_eq!(built.load(Ordering::Relaxed), 2);
+    assert_eq!(MockRt::block_on(addr.ask(1)), Err(AskError::Closed(1)));
+}
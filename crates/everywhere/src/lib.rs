@@ -9,38 +9,52 @@
 //! * 📡  [`everywhere::net`]      – portable WebSocket
 //! * ⚙️  [`everywhere::runtime`] – `spawn / sleep / channel` on any target
 //! * ⏲️  [`everywhere::timer`]   – exponential back-off helper
+//! * 🎭  [`everywhere::actor`]   – typed, supervised actors
 //! * ✅  [`everywhere::test`]    – one attribute for all async test harnesses
 //! * 🔊  [`everywhere::audio`]   – PTT / voice-note goodies (host only)
 //!
 //! ### Choosing the target
-//! Exactly **one** of `native | browser | wasi` must be enabled – just like
-//! in the individual crates.
+//! Exactly **one** of `native | smol | browser | wasi` must be enabled –
+//! just like in the individual crates. `smol` is the Tokio‑free native
+//! stack; it does not cover `net` yet.
 
 #![cfg_attr(
     not(feature = "native"),
-    cfg_attr(not(feature = "browser"),
-        cfg_attr(not(feature = "wasi"), no_std))
+    cfg_attr(not(feature = "smol"),
+        cfg_attr(not(feature = "browser"),
+            cfg_attr(not(feature = "wasi"), no_std)))
 )]
 
 /*──────────────────── target sanity check ────────────────────*/
 #[cfg(any(
+    all(feature = "native", feature = "smol"),
     all(feature = "native", feature = "browser"),
     all(feature = "native", feature = "wasi"),
+    all(feature = "smol", feature = "browser"),
+    all(feature = "smol", feature = "wasi"),
     all(feature = "browser", feature = "wasi"),
-    not(any(feature = "native", feature = "browser", feature = "wasi")),
+    not(any(feature = "native", feature = "smol", feature = "browser", feature = "wasi")),
 ))]
 compile_error!(
-    "Enable **exactly one** of: `native`, `browser`, or `wasi` on the \
-     `everywhere` crate (they forward to all sub-crates)."
+    "Enable **exactly one** of: `native`, `smol`, `browser`, or `wasi` on \
+     the `everywhere` crate (they forward to all sub-crates)."
 );
 
+#[cfg(all(feature = "smol", feature = "net"))]
+compile_error!("`net` runs on Tokio; use `native` instead of `smol`.");
+
 /*──────────────────── component re-exports ───────────────────*/
+#[cfg(feature = "actor")]
+pub use everywhere_actor   as actor;
 #[cfg(feature = "audio")]
 pub use everywhere_audio   as audio;
 #[cfg(feature = "net")]
 pub use everywhere_net     as net;
 #[cfg(feature = "runtime")]
 pub use everywhere_runtime as runtime;
+/// `#[everywhere::main] async fn main()` – see [`runtime::main`].
+#[cfg(feature = "runtime")]
+pub use everywhere_runtime::main;
 #[cfg(feature = "test")]
 pub use everywhere_test    as test;
 #[cfg(feature = "timer")]
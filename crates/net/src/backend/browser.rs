@@ -4,7 +4,7 @@ use super::super::{message::{WsError, WsMessage}, options::WsOptions};
 use anyhow::anyhow;
 use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use gloo_net::websocket::{futures::WebSocket as GlooWs, Message};
-use std::{pin::Pin, task::{Context, Poll}};
+use std::{pin::Pin, task::{Context, Poll}, time::Duration};
 
 pub struct WsConnection {
     sink: Pin<Box<dyn Sink<WsMessage, Error=WsError>>>,
@@ -13,6 +13,10 @@ pub struct WsConnection {
 
 impl WsConnection {
     pub(crate) async fn _connect_backend(url: &str, opt: &WsOptions) -> Result<Self, WsError> {
+        /* no handshake headers here: they ride in the query string ---- */
+        let url = opt.browser_url(url)?;
+        let url = url.as_str();
+
         /* choose correct `open*` flavour ------------------------------- */
         let ws = match opt.protocols.len() {
             0 => GlooWs::open(url)?,
@@ -32,6 +36,8 @@ impl WsConnection {
                     WsMessage::Binary(b) => Ok(Message::Bytes(b.into())),
                     WsMessage::Close(_) => Err(anyhow!(
                         "Browser backend: close via `WebSocket::close()` or drop the handle")),
+                    WsMessage::Ping(_) | WsMessage::Pong(_) => Err(anyhow!(
+                        "Browser backend: the browser handles ping/pong itself")),
                 }
             })
         );
@@ -49,6 +55,9 @@ impl WsConnection {
 
         Ok(Self { sink, stream })
     }
+
+    /// The browser keeps ping/pong to itself.
+    pub(crate) fn _rtt_backend(&self) -> Option<Duration> { None }
 }
 
 /*──── passthroughs ────────────────────────────────────────────────────*/
@@ -1,56 +1,285 @@
 //! Tokio + async‑tungstenite backend (desktop / server).
 
-use super::super::{message::{WsError, WsMessage}, options::WsOptions};
+use super::super::{message::{KeepaliveTimeout, WsError, WsMessage}, options::WsOptions};
 use anyhow::Context;
-use async_tungstenite::{tokio::connect_async, tungstenite::Message};
-use futures_util::{Sink, SinkExt, Stream, StreamExt};
-use std::{pin::Pin, task::{Context as Cx, Poll}};
+use async_tungstenite::{
+    tokio::connect_async_with_config,
+    tungstenite::{
+        client::IntoClientRequest,
+        handshake::client::Request,
+        http::{HeaderName, HeaderValue},
+        protocol::{CloseFrame, WebSocketConfig},
+        Error as WsErrorRaw, Message,
+    },
+};
+use everywhere_runtime::task;
+use futures_channel::oneshot;
+use futures_util::{
+    future::{self, BoxFuture, FusedFuture, Fuse},
+    lock::Mutex as AsyncMutex,
+    ready, stream, FutureExt, Sink, SinkExt, Stream, StreamExt,
+};
+use std::{
+    pin::{pin, Pin},
+    sync::{Arc, Mutex},
+    task::{Context as Cx, Poll},
+    time::Duration,
+};
+use tokio::time::{interval_at, sleep_until, timeout, Instant, MissedTickBehavior};
 
 /// TLS‑over‑TCP WebSocket.
 pub struct WsConnection {
     sink: Pin<Box<dyn Sink<WsMessage, Error=WsError> + Send>>,
     stream: Pin<Box<dyn Stream<Item=Result<WsMessage, WsError>> + Send>>,
+    probe: Option<Arc<Probe>>,
 }
 
 impl WsConnection {
     /// Backend–internal entry point (called by the public façade in `lib.rs`).
-    pub(crate) async fn _connect_backend(url: &str, _opt: &WsOptions) -> Result<Self, WsError> {
-        let (ws, _) = connect_async(url).await
+    pub(crate) async fn _connect_backend(url: &str, opt: &WsOptions) -> Result<Self, WsError> {
+        let (ws, _) = connect_async_with_config(request(url, opt)?, Some(config(opt))).await
             .with_context(|| format!("connect {url}"))?;
         let (sink_raw, stream_raw) = ws.split();
 
         /* outbound ----------------------------------------------------- */
-        let sink = Box::pin(
-            sink_raw.with(|m: WsMessage| async move {
-                let msg = match m {
-                    WsMessage::Text(t) => Message::Text(t),
-                    WsMessage::Binary(b) => Message::Binary(b.into()),
-                    WsMessage::Close(Some(c)) => Message::Close(Some(
-                        async_tungstenite::tungstenite::protocol::CloseFrame {
-                            code: c.0.into(),
-                            reason: std::borrow::Cow::Owned(c.1),
-                        })),
-                    WsMessage::Close(None) => Message::Close(None),
-                };
-                Ok::<_, WsError>(msg)
-            })
-        );
+        let probe = opt.ping_interval.map(|_| Arc::new(Probe::default()));
+        let (dead_tx, dead) = oneshot::channel();
+        let sink: Pin<Box<dyn Sink<WsMessage, Error=WsError> + Send>> = match (opt.ping_interval, &probe) {
+            (Some(every), Some(probe)) => Box::pin(
+                pinging(sink_raw, every, opt.pong_timeout, probe.clone(), dead_tx).with(outbound)
+            ),
+            _ => Box::pin(sink_raw.sink_map_err(WsError::from).with(outbound)),
+        };
 
         /* inbound ------------------------------------------------------ */
-        let stream = Box::pin(
-            stream_raw.map(|r| {
-                r.map(|m| match m {
-                    Message::Text(t) => WsMessage::Text(t),
-                    Message::Binary(b) => WsMessage::Binary(b.into()),
-                    Message::Close(c) => WsMessage::Close(
-                        c.map(|f| (f.code.into(), f.reason.into_owned()))
-                    ),
-                    _ => WsMessage::Close(None),
-                }).map_err(WsError::from)
-            })
-        );
-
-        Ok(Self { sink, stream })
+        /* tungstenite answers pings itself; pongs to our probes stay internal */
+        let ours = probe.clone();
+        let inbound = stream_raw.filter_map(move |r| future::ready(match r {
+            Ok(Message::Text(t)) => Some(Ok(WsMessage::Text(t))),
+            Ok(Message::Binary(b)) => Some(Ok(WsMessage::Binary(b.into()))),
+            Ok(Message::Ping(b)) => Some(Ok(WsMessage::Ping(b.into()))),
+            Ok(Message::Pong(b)) => match &ours {
+                Some(probe) if probe.pong(&b) => None,
+                _ => Some(Ok(WsMessage::Pong(b.into()))),
+            },
+            Ok(Message::Close(c)) => Some(Ok(WsMessage::Close(
+                c.map(|f| (f.code.into(), f.reason.into_owned()))
+            ))),
+            Ok(Message::Frame(_)) => None,
+            Err(e) => Some(Err(WsError::from(e))),
+        }));
+        /* a missed deadline cuts the stream short with one last error */
+        let dead = dead.shared();
+        let fired = dead.clone();
+        let inbound = inbound.take_until(async move {
+            if fired.await.is_err() { future::pending::<()>().await }    /* pinger gone, no timeout */
+        });
+        let timed_out = stream::once(future::lazy(move |_| dead.peek().cloned()))
+            .filter_map(|r| future::ready(r.and_then(Result::ok).map(|t| Err(t.into()))));
+        let stream = Box::pin(inbound.chain(timed_out).fuse());
+
+        Ok(Self { sink, stream, probe })
+    }
+
+    pub(crate) fn _rtt_backend(&self) -> Option<Duration> {
+        self.probe.as_ref().and_then(|p| p.rtt())
+    }
+}
+
+fn outbound(m: WsMessage) -> future::Ready<Result<Message, WsError>> {
+    future::ok(match m {
+        WsMessage::Text(t) => Message::Text(t),
+        WsMessage::Binary(b) => Message::Binary(b.into()),
+        WsMessage::Ping(b) => Message::Ping(b.into()),
+        WsMessage::Pong(b) => Message::Pong(b.into()),
+        WsMessage::Close(Some(c)) => Message::Close(Some(CloseFrame {
+            code: c.0.into(),
+            reason: std::borrow::Cow::Owned(c.1),
+        })),
+        WsMessage::Close(None) => Message::Close(None),
+    })
+}
+
+/// Handshake request: the URL with its query, `Sec-WebSocket-Protocol`
+/// and the caller's headers.
+fn request(url: &str, opt: &WsOptions) -> Result<Request, WsError> {
+    let mut req = opt.url(url)?.as_str().into_client_request()
+        .with_context(|| format!("bad url {url}"))?;
+    let headers = req.headers_mut();
+    if !opt.protocols.is_empty() {
+        let protocols = HeaderValue::from_str(&opt.protocols.join(", "))
+            .context("invalid sub‑protocol")?;
+        headers.insert("Sec-WebSocket-Protocol", protocols);
+    }
+    for (name, value) in &opt.headers {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .with_context(|| format!("invalid header name {name:?}"))?;
+        let value = HeaderValue::from_str(value)
+            .with_context(|| format!("invalid value for header {name}"))?;
+        headers.append(name, value);
+    }
+    Ok(req)
+}
+
+/// Size limits; `None` keeps tungstenite's defaults.
+fn config(opt: &WsOptions) -> WebSocketConfig {
+    let default = WebSocketConfig::default();
+    WebSocketConfig {
+        max_frame_size:   opt.max_frame_size.or(default.max_frame_size),
+        max_message_size: opt.max_message_size.or(default.max_message_size),
+        ..default
+    }
+}
+
+/// Keep‑alive bookkeeping shared by the pinging task and the inbound
+/// stream. Probe payloads are [`PROBE_TAG`] then a big‑endian sequence
+/// number, so a user ping can't pass for one of ours.
+const PROBE_TAG: &[u8] = b"everywhere-keepalive:";
+
+#[derive(Default)]
+struct Probe(Mutex<ProbeState>);
+
+#[derive(Default)]
+struct ProbeState {
+    last: u64,                    /* sequence of the newest ping */
+    sent: Option<Instant>,        /* when it went out */
+    unanswered: Option<Instant>,  /* oldest ping still without a pong */
+    rtt: Option<Duration>,
+}
+
+impl Probe {
+    fn state(&self) -> std::sync::MutexGuard<'_, ProbeState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Payload for the next ping.
+    fn ping(&self) -> Vec<u8> {
+        let mut st = self.state();
+        let now = Instant::now();
+        st.last += 1;
+        st.sent = Some(now);
+        st.unanswered.get_or_insert(now);
+        [PROBE_TAG, &st.last.to_be_bytes()].concat()
+    }
+
+    /// Whether `payload` answers one of our pings. Only the newest one
+    /// clears the deadline; a late pong to an older ping says nothing
+    /// about the ones after it.
+    fn pong(&self, payload: &[u8]) -> bool {
+        let Some(seq) = payload.strip_prefix(PROBE_TAG)
+            .and_then(|b| <[u8; 8]>::try_from(b).ok())
+            .map(u64::from_be_bytes) else { return false };
+        let mut st = self.state();
+        if seq == 0 || seq > st.last { return false; }
+        if seq == st.last {
+            st.rtt = st.sent.map(|t| t.elapsed());
+            st.unanswered = None;
+        }
+        true
+    }
+
+    fn unanswered(&self) -> Option<Instant> { self.state().unanswered }
+    fn rtt(&self) -> Option<Duration> { self.state().rtt }
+}
+
+/// Share `sink` with a task that sends a `Ping` every `every`. With a
+/// `deadline`, a ping left unanswered that long fires `dead` and closes
+/// the link (waiting at most `deadline` more for the Close frame). The task
+/// never waits on a send to watch the deadline, and it stops once the
+/// returned half is closed or dropped.
+fn pinging<S>(
+    sink: S,
+    every: Duration,
+    deadline: Option<Duration>,
+    probe: Arc<Probe>,
+    dead: oneshot::Sender<KeepaliveTimeout>,
+) -> Outbound<S>
+where
+    S: Sink<Message, Error=WsErrorRaw> + Unpin + Send + 'static,
+{
+    let sink = Arc::new(AsyncMutex::new(sink));
+    let (stop, mut stopped) = oneshot::channel::<()>();
+    let shared = sink.clone();
+    task::spawn(async move {
+        let mut tick = interval_at(Instant::now() + every, every);
+        tick.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut ping = pin!(Fuse::terminated());
+        loop {
+            let due = deadline.zip(probe.unanswered()).map(|(d, since)| since + d);
+            tokio::select! {
+                _ = &mut stopped => return,
+                _ = tick.tick(), if ping.is_terminated() => {
+                    ping.set(send_locked(shared.clone(), Message::Ping(probe.ping())).fuse());
+                }
+                sent = &mut ping, if !ping.is_terminated() => if sent.is_err() { return },
+                _ = sleep_until(due.unwrap_or_else(Instant::now)), if due.is_some() => match deadline {
+                    Some(d) if probe.unanswered().is_some_and(|since| since.elapsed() >= d) => {
+                        let _ = dead.send(KeepaliveTimeout { deadline: d });
+                        ping.set(Fuse::terminated());          /* releases the sink */
+                        let _ = timeout(d, async {
+                            let mut sink = shared.lock().await;
+                            let _ = sink.send(Message::Close(None)).await;
+                            sink.close().await
+                        }).await;
+                        return;
+                    }
+                    _ => {}               /* a pong landed since `due` was computed */
+                },
+            }
+        }
+    });
+    Outbound { sink, busy: None, stop: Some(stop) }
+}
+
+async fn send_locked<S>(sink: Arc<AsyncMutex<S>>, m: Message) -> Result<(), WsError>
+where
+    S: Sink<Message, Error=WsErrorRaw> + Unpin,
+{
+    Ok(sink.lock().await.send(m).await?)
+}
+
+/// The caller's half of a sink shared with [`pinging`]: each message is
+/// written and flushed before `send` returns, so errors surface on the
+/// call that hit them.
+struct Outbound<S> {
+    sink: Arc<AsyncMutex<S>>,
+    busy: Option<BoxFuture<'static, Result<(), WsError>>>,
+    stop: Option<oneshot::Sender<()>>,        /* dropping it ends the pings */
+}
+
+impl<S> Outbound<S> {
+    fn settle(&mut self, cx: &mut Cx<'_>) -> Poll<Result<(), WsError>> {
+        let Some(busy) = &mut self.busy else { return Poll::Ready(Ok(())) };
+        let done = ready!(busy.as_mut().poll(cx));
+        self.busy = None;
+        Poll::Ready(done)
+    }
+}
+
+impl<S> Sink<Message> for Outbound<S>
+where
+    S: Sink<Message, Error=WsErrorRaw> + Unpin + Send + 'static,
+{
+    type Error = WsError;
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Cx<'_>) -> Poll<Result<(), WsError>> {
+        self.get_mut().settle(cx)
+    }
+    fn start_send(self: Pin<&mut Self>, m: Message) -> Result<(), WsError> {
+        let this = self.get_mut();
+        this.busy = Some(Box::pin(send_locked(this.sink.clone(), m)));
+        Ok(())
+    }
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Cx<'_>) -> Poll<Result<(), WsError>> {
+        self.get_mut().settle(cx)
+    }
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Cx<'_>) -> Poll<Result<(), WsError>> {
+        let this = self.get_mut();
+        ready!(this.settle(cx))?;
+        if this.stop.take().is_some() {
+            let sink = this.sink.clone();
+            this.busy = Some(Box::pin(async move { Ok(sink.lock().await.close().await?) }));
+        }
+        this.settle(cx)
     }
 }
 
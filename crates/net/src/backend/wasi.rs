@@ -1,9 +1,17 @@
-//! soketto client (wasm32‑wasi or other pure‑WASI target).
+//! soketto client over `async_std::net` (wasm32‑wasi or other pure‑WASI
+//! target). Plain `ws://` only – there is no TLS here.
 
 use super::super::{message::{WsError, WsMessage}, options::WsOptions};
-use futures_util::{Sink, SinkExt, Stream, StreamExt};
-use soketto::{data::Message, handshake::Client};
-use std::{pin::Pin, task::{Context, Poll}};
+use anyhow::{anyhow, bail, Context as _};
+use async_std::net::TcpStream;
+use bytes::Bytes;
+use futures_util::{sink, stream, Sink, Stream};
+use soketto::{
+    connection::{Error as SokettoError, Receiver, Sender},
+    handshake::{client::Header, Client, ServerResponse},
+    data::ByteSlice125, Data, Incoming,
+};
+use std::{pin::Pin, task::{Context, Poll}, time::Duration};
 
 pub struct WsConnection {
     sink:   Pin<Box<dyn Sink  <WsMessage, Error = WsError>>>,
@@ -11,30 +19,82 @@ pub struct WsConnection {
 }
 
 impl WsConnection {
-    pub(crate) async fn _connect_backend(url: &str, _opt: &WsOptions) -> Result<Self, WsError> {
-        let mut client = Client::connect(url).await?;
-        client.handshake().await?;
-        let (sender, receiver) = client.into_builder().finish();
-
-        let sink = Box::pin(sender.with(|m: WsMessage| async move {
-            Ok(match m {
-                WsMessage::Text(t)   => Message::text(t.into()),
-                WsMessage::Binary(b) => Message::binary(b.into()),
-                WsMessage::Close(_)  => Message::close(None),
-            })
+    pub(crate) async fn _connect_backend(url: &str, opt: &WsOptions) -> Result<Self, WsError> {
+        let url = opt.url(url)?;
+        if url.scheme() != "ws" { bail!("WASI backend supports ws:// only, not {}://", url.scheme()); }
+        let host = url.host_str().context("url has no host")?;
+        let port = url.port_or_known_default().unwrap_or(80);
+        let authority = match url.port() {
+            Some(p) => format!("{host}:{p}"),
+            None    => host.to_owned(),
+        };
+        let resource = match url.query() {
+            Some(q) => format!("{}?{q}", url.path()),
+            None    => url.path().to_owned(),
+        };
+
+        let socket = TcpStream::connect((host, port)).await
+            .with_context(|| format!("connect {url}"))?;
+        let headers: Vec<Header> = opt.headers.iter()
+            .map(|(name, value)| Header { name, value: value.as_bytes() })
+            .collect();
+        let mut client = Client::new(socket, &authority, &resource);
+        client.set_headers(&headers);
+        for p in &opt.protocols { client.add_protocol(p); }
+        match client.handshake().await? {
+            ServerResponse::Accepted { .. } => {}
+            ServerResponse::Redirect { status_code, location } =>
+                bail!("handshake redirected ({status_code}) to {location}"),
+            ServerResponse::Rejected { status_code } =>
+                bail!("handshake rejected ({status_code})"),
+        }
+
+        let mut builder = client.into_builder();
+        if let Some(n) = opt.max_frame_size   { builder.set_max_frame_size(n); }
+        if let Some(n) = opt.max_message_size { builder.set_max_message_size(n); }
+        let (sender, receiver) = builder.finish();
+
+        let sink = Box::pin(sink::unfold(sender, |mut tx: Sender<TcpStream>, m: WsMessage| async move {
+            match m {
+                WsMessage::Text(t)   => tx.send_text_owned(t).await?,
+                WsMessage::Binary(b) => tx.send_binary(&b).await?,
+                WsMessage::Ping(b)   => tx.send_ping(control(&b)?).await?,
+                WsMessage::Pong(b)   => tx.send_pong(control(&b)?).await?,
+                WsMessage::Close(_)  => tx.close().await?,
+            }
+            tx.flush().await?;
+            Ok::<_, WsError>(tx)
         }));
 
-        let stream = Box::pin(
-            receiver.map(|r| {
-                r.map(|m| match m {
-                    Message::Text(t)   => WsMessage::Text(String::from_utf8_lossy(&t).into()),
-                    Message::Binary(b) => WsMessage::Binary(b.into()),
-                    Message::Close(_)  => WsMessage::Close(None),
-                }).map_err(WsError::from)
-            })
-        );
+        let stream = Box::pin(stream::unfold(Some(receiver), |rx| async move {
+            let mut rx = rx?;
+            let next = receive(&mut rx).await;
+            let more = matches!(next, Ok(WsMessage::Text(_) | WsMessage::Binary(_) | WsMessage::Pong(_)));
+            Some((next, more.then_some(rx)))
+        }));
         Ok(Self { sink, stream })
     }
+
+    /// No keep‑alive here, hence no round trips.
+    pub(crate) fn _rtt_backend(&self) -> Option<Duration> { None }
+}
+
+/// Control payloads are capped at 125 bytes.
+fn control(b: &[u8]) -> Result<ByteSlice125<'_>, WsError> {
+    ByteSlice125::try_from(b).map_err(|_| anyhow!("control frame payload over 125 bytes"))
+}
+
+/// Next data, pong or close message; soketto answers pings itself.
+async fn receive(rx: &mut Receiver<TcpStream>) -> Result<WsMessage, WsError> {
+    let mut buf = Vec::new();
+    match rx.receive(&mut buf).await {
+        Ok(Incoming::Data(Data::Text(_)))   => Ok(WsMessage::Text(String::from_utf8(buf)?)),
+        Ok(Incoming::Data(Data::Binary(_))) => Ok(WsMessage::Binary(buf.into())),
+        Ok(Incoming::Pong(p))               => Ok(WsMessage::Pong(Bytes::copy_from_slice(p))),
+        Ok(Incoming::Closed(r))             => Ok(WsMessage::Close(Some((r.code, r.descr.unwrap_or_default())))),
+        Err(SokettoError::Closed)           => Ok(WsMessage::Close(None)),
+        Err(e)                              => Err(anyhow!(e)),
+    }
 }
 
 /*──── passthroughs ────────────────────────────────────────────────────*/
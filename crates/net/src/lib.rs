@@ -24,9 +24,13 @@
 pub mod message;
 mod backend;
 mod options;
+mod reconnect;
 
-pub use message::{WsError, WsMessage};
+pub use message::{KeepaliveTimeout, WsError, WsMessage};
 pub use options::WsOptions;
+pub use reconnect::{DropPolicy, ReconnectBuilder, ReconnectingWs, WsState};
+
+use core::time::Duration;
 
 /*──── re‑export the active backend struct ───────────────────────────────*/
 cfg_if::cfg_if! {
@@ -48,6 +52,10 @@ impl WsConnection {
     pub async fn connect_with(url: &str, opts: &WsOptions) -> Result<Self, WsError> {
         Self::_connect_backend(url, opts).await
     }
+
+    /// Round trip of the latest answered keep‑alive ping; `None` until
+    /// one comes back or without [`WsOptions::ping_interval`].
+    pub fn rtt(&self) -> Option<Duration> { self._rtt_backend() }
 }
 
 /*──── convenience glob ──────────────────────────────────────────────────*/
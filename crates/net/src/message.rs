@@ -1,6 +1,6 @@
 //! Shared message + trait aliases (no more overlapping impls).
 use bytes::Bytes;
-use core::fmt;
+use core::{fmt, time::Duration};
 use futures_util::{Sink, Stream};
 
 /// Cheap‑to‑clone WebSocket message.
@@ -9,6 +9,10 @@ pub enum WsMessage {
 
     Text(String),
     Binary(Bytes),
+    /// Control frames (≤ 125 bytes). Incoming pings are answered by the
+    /// backend; the browser neither shows nor sends either.
+    Ping(Bytes),
+    Pong(Bytes),
     Close(Option<(u16, String)>),
 }
 impl fmt::Debug for WsMessage {
@@ -16,6 +20,8 @@ impl fmt::Debug for WsMessage {
         match self {
             Self::Text(t)   => f.debug_tuple("Text").field(t).finish(),
             Self::Binary(b) => f.debug_tuple("Binary").field(&b.len()).finish(),
+            Self::Ping(b)   => f.debug_tuple("Ping").field(&b.len()).finish(),
+            Self::Pong(b)   => f.debug_tuple("Pong").field(&b.len()).finish(),
             Self::Close(c)  => f.debug_tuple("Close").field(c).finish(),
         }
     }
@@ -24,6 +30,20 @@ impl fmt::Debug for WsMessage {
 /// Back‑ends bubble up anything as `anyhow::Error`.
 pub type WsError = anyhow::Error;
 
+/// The peer stopped answering keep‑alive pings (see
+/// [`WsOptions::keepalive`](crate::WsOptions::keepalive)); find it with
+/// `err.downcast_ref::<KeepaliveTimeout>()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeepaliveTimeout {
+    pub deadline: Duration,
+}
+impl fmt::Display for KeepaliveTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no pong within {:?}", self.deadline)
+    }
+}
+impl std::error::Error for KeepaliveTimeout {}
+
 /*──────────────────────── trait alias ────────────────────────*/
 #[cfg(not(target_arch = "wasm32"))]
 pub trait WebSocketLike:
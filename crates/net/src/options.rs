@@ -1,16 +1,32 @@
 //! Small, cross‑platform set of connection tweaks.
 
+use super::message::WsError;
+use anyhow::Context;
 use std::time::Duration;
+use url::Url;
 
 /// Builder for run‑time settings.
 ///
 /// Back‑ends silently ignore knobs they cannot honour.
+///
+/// # Handshake headers in the browser
+/// Browsers cannot set headers on a WebSocket handshake, so the browser
+/// backend moves them into the query string (see [`browser_url`]):
+///
+/// * `bearer(token)` becomes `access_token=<token>` (RFC 6750 §2.3);
+/// * any other `header(name, value)` becomes `name=value`;
+/// * `Cookie` and `Origin` are dropped – the browser sets those itself.
+///
+/// [`browser_url`]: WsOptions::browser_url
 #[derive(Clone, Debug, Default)]
 pub struct WsOptions {
     pub protocols:         Vec<String>,
+    pub headers:           Vec<(String, String)>,  // native / WASI; browser → query
+    pub query:             Vec<(String, String)>,
     pub max_frame_size:    Option<usize>,       // native / WASI
     pub max_message_size:  Option<usize>,       // native / WASI
-    pub ping_interval:     Option<Duration>,    // native / WASI
+    pub ping_interval:     Option<Duration>,    // native
+    pub pong_timeout:      Option<Duration>,    // native
 }
 
 impl WsOptions {
@@ -20,7 +36,60 @@ impl WsOptions {
     pub fn protocol(mut self, p: impl Into<String>) -> Self {
         self.protocols.push(p.into()); self
     }
+    /// Extra handshake header; repeat a name to send it several times.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into())); self
+    }
+    /// `Authorization: Bearer <token>`.
+    pub fn bearer(self, token: impl AsRef<str>) -> Self {
+        self.header("Authorization", format!("Bearer {}", token.as_ref()))
+    }
+    /// Query parameter appended (percent‑encoded) to the URL.
+    pub fn query(mut self, k: impl Into<String>, v: impl Into<String>) -> Self {
+        self.query.push((k.into(), v.into())); self
+    }
     pub fn max_frame_size   (mut self, n: usize   ) -> Self { self.max_frame_size   = Some(n); self }
     pub fn max_message_size (mut self, n: usize   ) -> Self { self.max_message_size = Some(n); self }
     pub fn ping_interval    (mut self, d: Duration) -> Self { self.ping_interval    = Some(d); self }
+    /// Ping every `every`; if a ping goes unanswered for `deadline` the
+    /// stream yields a [`KeepaliveTimeout`] error and the link is dropped.
+    ///
+    /// [`KeepaliveTimeout`]: crate::KeepaliveTimeout
+    pub fn keepalive(mut self, every: Duration, deadline: Duration) -> Self {
+        self.ping_interval = Some(every);
+        self.pong_timeout  = Some(deadline);
+        self
+    }
+
+    /*── URLs the backends dial ────────────────────────────────────────*/
+
+    /// `url` plus the [`query`](Self::query) parameters.
+    pub fn url(&self, url: &str) -> Result<Url, WsError> {
+        with_query(url, self.query.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+    }
+
+    /// What the browser backend dials: [`url`](Self::url) plus the
+    /// headers folded into the query string (see the type docs).
+    pub fn browser_url(&self, url: &str) -> Result<Url, WsError> {
+        let headers = self.headers.iter().filter_map(|(name, value)| {
+            match name.to_ascii_lowercase().as_str() {
+                "cookie" | "origin" => None,
+                "authorization"     => Some(match value.strip_prefix("Bearer ") {
+                    Some(token) => ("access_token", token),
+                    None        => ("authorization", value.as_str()),
+                }),
+                _                   => Some((name.as_str(), value.as_str())),
+            }
+        });
+        with_query(url, self.query.iter().map(|(k, v)| (k.as_str(), v.as_str())).chain(headers))
+    }
+}
+
+fn with_query<'a>(url: &str, pairs: impl Iterator<Item = (&'a str, &'a str)>) -> Result<Url, WsError> {
+    let mut url = Url::parse(url).with_context(|| format!("bad url {url}"))?;
+    let mut pairs = pairs.peekable();
+    if pairs.peek().is_some() {
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+    Ok(url)
 }
@@ -0,0 +1,355 @@
+//! Self‑healing connection: [`ReconnectingWs`] redials with an
+//! [`everywhere_timer::Timer`] back‑off and hides the gaps from the caller.
+//!
+//! ```no_run
+//! use everywhere_net::{prelude::*, DropPolicy, ReconnectingWs};
+//! use std::time::Duration;
+//!
+//! # async fn demo() {
+//! let mut ws = ReconnectingWs::builder("wss://feed.example/ws")
+//!     .backoff(|n| Duration::from_millis(100 << n.min(6)))
+//!     .buffer(64, DropPolicy::Oldest)
+//!     .on_connect(|| vec![WsMessage::Text("subscribe".into())])
+//!     .connect();
+//!
+//! ws.send(WsMessage::Text("hello".into()));     // queued while down
+//! while let Some(msg) = ws.next().await { /* … */ }
+//! # }
+//! ```
+
+use super::{message::WsMessage, options::WsOptions, WsConnection};
+use core::{
+    fmt,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+    time::Duration,
+};
+use everywhere_runtime::{
+    prelude::Runtime, sync::Notify, task, BroadcastRecv, BroadcastRecvError, BroadcastSend, Rt,
+    SenderExt,
+};
+use everywhere_timer::Timer;
+use futures_util::{
+    future::{self, Either},
+    stream, SinkExt, Stream, StreamExt,
+};
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/*──── public knobs ──────────────────────────────────────────────────────*/
+
+/// Where a [`ReconnectingWs`] is in its life cycle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WsState {
+    Connecting,
+    Open,
+    /// Waiting before retry number `n` (1‑based; resets once connected).
+    Backoff(usize),
+    /// Closed by the caller; terminal.
+    Closed,
+}
+
+/// Which message a full outbound buffer gives up.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// Evict the oldest queued message to make room.
+    #[default]
+    Oldest,
+    /// Refuse the new message.
+    Newest,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type Hook = dyn FnMut() -> Vec<WsMessage> + Send + 'static;
+#[cfg(target_arch = "wasm32")]
+type Hook = dyn FnMut() -> Vec<WsMessage> + 'static;
+
+type Backoff = dyn Fn(usize) -> Duration + Send + Sync + 'static;
+
+/// Settings for [`ReconnectingWs`]; finish with [`connect`](Self::connect).
+pub struct ReconnectBuilder {
+    url: String,
+    options: WsOptions,
+    backoff: Arc<Backoff>,
+    capacity: usize,
+    policy: DropPolicy,
+    on_connect: Option<Box<Hook>>,
+}
+
+impl ReconnectBuilder {
+    /// Handshake options for every attempt.
+    pub fn options(mut self, options: WsOptions) -> Self { self.options = options; self }
+
+    /// Delay before retry `n` (1‑based); default doubles from 100 ms up
+    /// to ~6 s.
+    pub fn backoff<F>(mut self, calc: F) -> Self
+    where
+        F: Fn(usize) -> Duration + Send + Sync + 'static,
+    {
+        self.backoff = Arc::new(calc);
+        self
+    }
+
+    /// Queue at most `capacity` outbound messages; `policy` picks the
+    /// victim once full. Default: 256, [`DropPolicy::Oldest`].
+    pub fn buffer(mut self, capacity: usize, policy: DropPolicy) -> Self {
+        self.capacity = capacity;
+        self.policy = policy;
+        self
+    }
+
+    /// Messages sent first on every (re)connect – e.g. subscriptions.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn on_connect<F>(mut self, hook: F) -> Self
+    where
+        F: FnMut() -> Vec<WsMessage> + Send + 'static,
+    {
+        self.on_connect = Some(Box::new(hook));
+        self
+    }
+    #[cfg(target_arch = "wasm32")]
+    pub fn on_connect<F>(mut self, hook: F) -> Self
+    where
+        F: FnMut() -> Vec<WsMessage> + 'static,
+    {
+        self.on_connect = Some(Box::new(hook));
+        self
+    }
+
+    /// Start dialling in the background.
+    pub fn connect(self) -> ReconnectingWs {
+        let shared = Arc::new(Shared {
+            outbox: Mutex::new(Outbox {
+                queue: VecDeque::new(),
+                capacity: self.capacity,
+                policy: self.policy,
+                closed: false,
+            }),
+            wake: Notify::new(),
+            state: Mutex::new(WsState::Connecting),
+        });
+        let (inbound_tx, inbound) = task::channel();
+        let (states, _) = task::broadcast(16);
+        let backoff = self.backoff;
+        let driver = Driver {
+            shared: shared.clone(),
+            url: self.url,
+            options: self.options,
+            timer: Timer::new(|| {}, move |n| backoff(n)),
+            on_connect: self.on_connect,
+            inbound: inbound_tx,
+            states: states.clone(),
+        };
+        drop(task::spawn(driver.run()));
+        ReconnectingWs { shared, inbound, states }
+    }
+}
+
+/*──── handle ────────────────────────────────────────────────────────────*/
+
+/// A [`WsConnection`] that keeps coming back.
+///
+/// * outbound messages queue in a bounded buffer and go out once a
+///   connection is up – a message in flight when the link dies is lost;
+/// * inbound messages arrive through [`Stream`]; drops and server
+///   `Close` frames only show up in [`states`](Self::states);
+/// * with [`WsOptions::keepalive`] a silent peer counts as a drop;
+/// * dropping the handle (or [`close`](Self::close)) ends the session.
+pub struct ReconnectingWs {
+    shared: Arc<Shared>,
+    inbound: <Rt as Runtime>::Receiver<WsMessage>,
+    states: <Rt as Runtime>::BroadcastSender<WsState>,
+}
+
+impl ReconnectingWs {
+    /// Start configuring a connection to `url`.
+    pub fn builder(url: impl Into<String>) -> ReconnectBuilder {
+        ReconnectBuilder {
+            url: url.into(),
+            options: WsOptions::default(),
+            backoff: Arc::new(|n| Duration::from_millis(100 << n.clamp(1, 7).saturating_sub(1))),
+            capacity: 256,
+            policy: DropPolicy::Oldest,
+            on_connect: None,
+        }
+    }
+
+    /// Queue `msg`; returns whatever the [`DropPolicy`] discarded
+    /// (or `msg` itself once closed).
+    pub fn send(&self, msg: WsMessage) -> Option<WsMessage> {
+        let dropped = self.shared.outbox().push(msg);
+        self.shared.wake.notify_one();
+        dropped
+    }
+
+    /// Messages waiting for a connection.
+    pub fn queued(&self) -> usize { self.shared.outbox().queue.len() }
+
+    pub fn state(&self) -> WsState { *lock(&self.shared.state) }
+
+    /// The current state, then every change; ends after
+    /// [`WsState::Closed`].
+    pub fn states(&self) -> impl Stream<Item = WsState> + Unpin + 'static {
+        let (now, rx) = {
+            let now = lock(&self.shared.state);          /* no change slips in between */
+            (*now, self.states.subscribe())
+        };
+        Box::pin(stream::unfold(Some((Some(now), rx)), |st| async move {
+            let (first, mut rx) = st?;
+            let s = match first {
+                Some(s) => s,
+                None => loop {
+                    match rx.recv().await {
+                        Ok(s) => break s,
+                        Err(BroadcastRecvError::Lagged(_)) => continue,
+                        Err(BroadcastRecvError::Closed) => return None,
+                    }
+                },
+            };
+            Some((s, (s != WsState::Closed).then_some((None, rx))))
+        }))
+    }
+
+    /// Send a `Close` frame if connected and stop reconnecting.
+    pub fn close(&self) {
+        self.shared.outbox().closed = true;
+        self.shared.wake.notify_one();
+    }
+}
+
+impl Drop for ReconnectingWs {
+    fn drop(&mut self) { self.close(); }
+}
+
+impl fmt::Debug for ReconnectingWs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReconnectingWs")
+            .field("state", &self.state())
+            .field("queued", &self.queued())
+            .finish()
+    }
+}
+
+impl Stream for ReconnectingWs {
+    type Item = WsMessage;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<WsMessage>> {
+        self.inbound.poll_next_unpin(cx)
+    }
+}
+
+/*──── shared state ──────────────────────────────────────────────────────*/
+
+struct Shared {
+    outbox: Mutex<Outbox>,
+    wake: Notify,                 /* outbox grew or was closed */
+    state: Mutex<WsState>,
+}
+
+struct Outbox {
+    queue: VecDeque<WsMessage>,
+    capacity: usize,
+    policy: DropPolicy,
+    closed: bool,
+}
+
+impl Outbox {
+    fn push(&mut self, msg: WsMessage) -> Option<WsMessage> {
+        if self.closed { return Some(msg); }
+        if self.queue.len() < self.capacity {
+            self.queue.push_back(msg);
+            return None;
+        }
+        match self.policy {
+            DropPolicy::Newest => Some(msg),
+            DropPolicy::Oldest => {
+                self.queue.push_back(msg);
+                self.queue.pop_front()
+            }
+        }
+    }
+}
+
+fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> { m.lock().unwrap_or_else(|e| e.into_inner()) }
+
+impl Shared {
+    fn outbox(&self) -> MutexGuard<'_, Outbox> { lock(&self.outbox) }
+    fn closed(&self) -> bool { self.outbox().closed }
+}
+
+/*──── background driver ─────────────────────────────────────────────────*/
+
+struct Driver {
+    shared: Arc<Shared>,
+    url: String,
+    options: WsOptions,
+    timer: Timer,
+    on_connect: Option<Box<Hook>>,
+    inbound: <Rt as Runtime>::Sender<WsMessage>,
+    states: <Rt as Runtime>::BroadcastSender<WsState>,
+}
+
+/// How a live connection ended.
+enum Ended { Lost, ByCaller }
+
+impl Driver {
+    fn set(&self, s: WsState) {
+        let mut now = lock(&self.shared.state);
+        if *now == s { return; }
+        *now = s;
+        let _ = self.states.send(s);
+    }
+
+    async fn run(mut self) {
+        while !self.shared.closed() {
+            self.set(WsState::Connecting);
+            if let Ok(ws) = WsConnection::connect_with(&self.url, &self.options).await {
+                self.timer.reset();
+                self.set(WsState::Open);
+                if let Ended::ByCaller = self.pump(ws).await { break; }
+            }
+            if self.shared.closed() { break; }
+
+            /* back off, but give up early if the caller closes */
+            let mut wait = self.timer.schedule_timeout();
+            self.set(WsState::Backoff(self.timer.tries()));
+            loop {
+                let closing = pin!(self.shared.wake.notified());
+                if self.shared.closed() { wait.abort(); break; }
+                match future::select(closing, wait).await {
+                    Either::Left((_, pending)) => { wait = pending; }
+                    Either::Right(_) => break,
+                }
+            }
+        }
+        self.set(WsState::Closed);
+    }
+
+    /// Shuttle messages both ways until the link drops or the caller
+    /// closes.
+    async fn pump(&mut self, ws: WsConnection) -> Ended {
+        let (mut sink, mut stream) = ws.split();
+        let greeting = self.on_connect.as_mut().map(|hook| hook()).unwrap_or_default();
+        for msg in greeting {
+            if sink.send(msg).await.is_err() { return Ended::Lost; }
+        }
+        loop {
+            let woken = self.shared.wake.notified();
+            loop {
+                let next = self.shared.outbox().queue.pop_front();
+                let Some(msg) = next else { break };
+                if sink.send(msg).await.is_err() { return Ended::Lost; }
+            }
+            if self.shared.closed() {
+                let _ = sink.send(WsMessage::Close(None)).await;
+                return Ended::ByCaller;
+            }
+            match future::select(pin!(woken), stream.next()).await {
+                Either::Left(_) => {}
+                Either::Right((Some(Ok(WsMessage::Close(_)) | Err(_)) | None, _)) => return Ended::Lost,
+                Either::Right((Some(Ok(msg)), _)) => { let _ = self.inbound.send_owned(msg); }
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Local tungstenite server shared by the backend tests.
+#![allow(dead_code)]
+
+use async_tungstenite::{
+    tokio::{accept_hdr_async, TokioAdapter},
+    tungstenite::handshake::server::{Request, Response},
+    WebSocketStream,
+};
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+};
+use tokio::{net::{TcpListener, TcpStream}, task::JoinHandle};
+
+pub type ServerWs = WebSocketStream<TokioAdapter<TcpStream>>;
+
+/// Accept one client, run `handler` on it; the handle yields the
+/// client's handshake request.
+#[allow(clippy::result_large_err)]          // tungstenite's callback signature
+pub async fn serve<F, Fut>(handler: F) -> (String, JoinHandle<Request>)
+where
+    F: FnOnce(ServerWs) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (tcp, _) = listener.accept().await.unwrap();
+        let seen = Arc::new(Mutex::new(None));
+        let s = seen.clone();
+        let ws = accept_hdr_async(tcp, move |req: &Request, res: Response| {
+            *s.lock().unwrap() = Some(req.clone());
+            Ok(res)
+        }).await.unwrap();
+        handler(ws).await;
+        let req = seen.lock().unwrap().take().unwrap();
+        req
+    });
+    (url, server)
+}
+
+/// Values of header `name` in `req`, in order.
+pub fn header<'a>(req: &'a Request, name: &str) -> Vec<&'a str> {
+    req.headers().get_all(name).iter().map(|v| v.to_str().unwrap()).collect()
+}
+
+/// Accept clients forever on a thread of its own, so the test may run
+/// on any executor; connection `n` (from 0) runs `handler(n, ws)`.
+pub fn serve_each<F, Fut>(handler: F) -> String
+where
+    F: Fn(usize, ServerWs) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        rt.block_on(async move {
+            let listener = TcpListener::from_std(listener).unwrap();
+            for n in 0.. {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let Ok(ws) = async_tungstenite::tokio::accept_async(tcp).await else { continue };
+                tokio::spawn(handler(n, ws));
+            }
+        });
+    });
+    url
+}
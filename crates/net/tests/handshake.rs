@@ -0,0 +1,54 @@
+//! Custom handshake headers and query parameters on the backends that
+//! can send them.
+#![cfg(all(not(target_arch = "wasm32"), any(feature = "native", feature = "wasi")))]
+
+mod common;
+
+use common::{header, serve};
+use everywhere_net::prelude::*;
+
+#[tokio::test]
+async fn headers_reach_the_server() {
+    let (url, server) = serve(|_| async {}).await;
+    let opts = WsOptions::new()
+        .bearer("s3cret")
+        .header("X-Api-Key", "k1")
+        .header("Cookie", "a=1")
+        .header("Cookie", "b=2")
+        .header("Origin", "https://app.example");
+    let _ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    let req = server.await.unwrap();
+    assert_eq!(header(&req, "Authorization"), ["Bearer s3cret"]);
+    assert_eq!(header(&req, "X-Api-Key"), ["k1"]);
+    assert_eq!(header(&req, "Cookie"), ["a=1", "b=2"]);
+    assert_eq!(header(&req, "Origin"), ["https://app.example"]);
+}
+
+#[tokio::test]
+async fn query_is_appended_and_encoded() {
+    let (url, server) = serve(|_| async {}).await;
+    let opts = WsOptions::new().query("room", "a b").query("v", "2");
+    let _ws = WsConnection::connect_with(&format!("{url}/chat?x=1"), &opts).await.unwrap();
+
+    let req = server.await.unwrap();
+    assert_eq!(req.uri().path(), "/chat");
+    assert_eq!(req.uri().query(), Some("x=1&room=a+b&v=2"));
+}
+
+#[tokio::test]
+async fn echo_after_custom_handshake() {
+    let (url, _server) = serve(|mut ws| async move {
+        while let Some(Ok(m)) = ws.next().await {
+            if m.is_close() { break; }
+            ws.send(m).await.unwrap();
+        }
+    }).await;
+    let opts = WsOptions::new().bearer("t").query("k", "v");
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    ws.send(WsMessage::Text("hi".into())).await.unwrap();
+    assert_eq!(ws.next().await.unwrap().unwrap(), WsMessage::Text("hi".into()));
+    ws.send(WsMessage::Binary(vec![1, 2, 3].into())).await.unwrap();
+    assert_eq!(ws.next().await.unwrap().unwrap(), WsMessage::Binary(vec![1, 2, 3].into()));
+}
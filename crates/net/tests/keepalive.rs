@@ -0,0 +1,130 @@
+//! Ping/Pong frames and keep‑alive against a local tungstenite server.
+#![cfg(all(not(target_arch = "wasm32"), any(feature = "native", feature = "wasi")))]
+
+mod common;
+
+use async_tungstenite::tungstenite::Message;
+use common::serve;
+use everywhere_net::prelude::*;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const MS: Duration = Duration::from_millis(1);
+
+/// Our pings reach the server and its (automatic) pongs come back.
+#[tokio::test]
+async fn ping_gets_a_pong() {
+    let (url, server) = serve(|mut ws| async move {
+        match ws.next().await {
+            Some(Ok(Message::Ping(p))) => assert_eq!(p, b"hi"),
+            other => panic!("expected a ping, got {other:?}"),
+        }
+        let _ = ws.next().await;                 // flushes the pong
+    }).await;
+    let mut ws = WsConnection::connect(&url).await.unwrap();
+
+    ws.send(WsMessage::Ping("hi".into())).await.unwrap();
+    let pong = timeout(5000 * MS, ws.next()).await.unwrap();
+    assert_eq!(pong.unwrap().unwrap(), WsMessage::Pong("hi".into()));
+    ws.send(WsMessage::Close(None)).await.unwrap();
+    server.await.unwrap();
+}
+
+/// The server's pings show up (already answered) instead of closing.
+#[cfg(feature = "native")]
+#[tokio::test]
+async fn server_ping_is_a_message() {
+    let (url, server) = serve(|mut ws| async move {
+        ws.send(Message::Ping(b"yo".to_vec())).await.unwrap();
+        match ws.next().await {
+            Some(Ok(Message::Pong(p))) => assert_eq!(p, b"yo"),
+            other => panic!("expected a pong, got {other:?}"),
+        }
+        ws.send(Message::Text("done".into())).await.unwrap();
+        let _ = ws.next().await;
+    }).await;
+    let mut ws = WsConnection::connect(&url).await.unwrap();
+
+    assert_eq!(ws.next().await.unwrap().unwrap(), WsMessage::Ping("yo".into()));
+    assert_eq!(ws.next().await.unwrap().unwrap(), WsMessage::Text("done".into()));
+    ws.send(WsMessage::Close(None)).await.unwrap();
+    server.await.unwrap();
+}
+
+/// Answered keep‑alive pings yield a round trip and stay invisible.
+#[cfg(feature = "native")]
+#[tokio::test]
+async fn keepalive_measures_rtt() {
+    let (url, _server) = serve(|mut ws| async move {
+        while let Some(Ok(_)) = ws.next().await {}
+    }).await;
+    let opts = WsOptions::new().keepalive(10 * MS, 1000 * MS);
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+    assert_eq!(ws.rtt(), None);
+
+    /* nothing but our own pongs arrives, so `next` never resolves */
+    assert!(timeout(100 * MS, ws.next()).await.is_err());
+    assert!(ws.rtt().is_some_and(|rtt| rtt < 1000 * MS));
+}
+
+/// A peer that stops reading never pongs: the stream reports it, then
+/// ends, and the peer gets a Close once it reads again.
+#[cfg(feature = "native")]
+#[tokio::test]
+async fn silent_peer_times_out() {
+    let (url, server) = serve(|mut ws| async move {
+        tokio::time::sleep(500 * MS).await;
+        loop {
+            match ws.next().await {
+                Some(Ok(Message::Close(_))) => break,
+                Some(Ok(Message::Ping(_))) => continue,
+                other => panic!("expected a close, got {other:?}"),
+            }
+        }
+    }).await;
+    let opts = WsOptions::new().keepalive(10 * MS, 50 * MS);
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    let err = timeout(5000 * MS, ws.next()).await.unwrap().unwrap().unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<everywhere_net::KeepaliveTimeout>(),
+        Some(&everywhere_net::KeepaliveTimeout { deadline: 50 * MS }),
+    );
+    assert_eq!(ws.rtt(), None);
+    assert!(ws.next().await.is_none());
+    server.await.unwrap();
+}
+
+/// A user ping whose payload looks like a bare sequence number is not
+/// mistaken for a keep‑alive probe.
+#[cfg(feature = "native")]
+#[tokio::test]
+async fn user_pong_is_not_a_probe() {
+    let (url, _server) = serve(|mut ws| async move {
+        while let Some(Ok(_)) = ws.next().await {}
+    }).await;
+    let opts = WsOptions::new().keepalive(1000 * MS, 1000 * MS);
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    let payload = 1u64.to_be_bytes().to_vec();
+    ws.send(WsMessage::Ping(payload.clone().into())).await.unwrap();
+    let pong = timeout(5000 * MS, ws.next()).await.unwrap();
+    assert_eq!(pong.unwrap().unwrap(), WsMessage::Pong(payload.into()));
+}
+
+/// With keep‑alive on, a send on a closed link fails on that call.
+#[cfg(feature = "native")]
+#[tokio::test]
+async fn send_error_reaches_the_caller() {
+    let (url, server) = serve(|mut ws| async move {
+        ws.close(None).await.unwrap();
+        while let Some(Ok(_)) = ws.next().await {}
+    }).await;
+    let opts = WsOptions::new().keepalive(1000 * MS, 1000 * MS);
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    assert_eq!(ws.next().await.unwrap().unwrap(), WsMessage::Close(None));
+    assert!(ws.send(WsMessage::Text("late".into())).await.is_err());
+    drop(ws);
+    server.await.unwrap();
+}
@@ -0,0 +1,88 @@
+//! `WsOptions` against a local tungstenite server (native backend only).
+#![cfg(feature = "native")]
+
+mod common;
+
+use async_tungstenite::tungstenite::Message;
+use common::{header, serve, ServerWs};
+use everywhere_net::prelude::*;
+use std::time::Duration;
+
+/// Server that sends a small and then a 4 KiB binary message.
+async fn small_then_big(mut ws: ServerWs) {
+    ws.send(Message::Binary(vec![1; 16])).await.unwrap();
+    ws.send(Message::Binary(vec![2; 4096])).await.unwrap();
+    let _ = ws.next().await;
+}
+
+#[tokio::test]
+async fn protocols_sent_in_handshake() {
+    let (url, server) = serve(|_| async {}).await;
+    let opts = WsOptions::new().protocol("json").protocol("cbor");
+    let _ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    assert_eq!(header(&server.await.unwrap(), "Sec-WebSocket-Protocol"), ["json, cbor"]);
+}
+
+#[tokio::test]
+async fn no_protocol_header_by_default() {
+    let (url, server) = serve(|_| async {}).await;
+    let _ws = WsConnection::connect(&url).await.unwrap();
+
+    assert!(header(&server.await.unwrap(), "Sec-WebSocket-Protocol").is_empty());
+}
+
+#[tokio::test]
+async fn defaults_accept_large_messages() {
+    let (url, _server) = serve(small_then_big).await;
+    let mut ws = WsConnection::connect(&url).await.unwrap();
+
+    assert!(matches!(ws.next().await, Some(Ok(WsMessage::Binary(b))) if b.len() == 16));
+    assert!(matches!(ws.next().await, Some(Ok(WsMessage::Binary(b))) if b.len() == 4096));
+}
+
+#[tokio::test]
+async fn max_message_size_rejects_larger_messages() {
+    let (url, _server) = serve(small_then_big).await;
+    let opts = WsOptions::new().max_message_size(1024);
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    assert!(matches!(ws.next().await, Some(Ok(WsMessage::Binary(b))) if b.len() == 16));
+    let err = ws.next().await.unwrap().unwrap_err();
+    assert!(err.to_string().contains("Message too long"), "{err}");
+}
+
+#[tokio::test]
+async fn max_frame_size_rejects_larger_frames() {
+    let (url, _server) = serve(small_then_big).await;
+    let opts = WsOptions::new().max_frame_size(1024);
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    assert!(matches!(ws.next().await, Some(Ok(WsMessage::Binary(b))) if b.len() == 16));
+    assert!(ws.next().await.unwrap().is_err());
+}
+
+/// Pings arrive on schedule and the pongs answering them never surface
+/// as messages.
+#[tokio::test]
+async fn ping_interval_sends_pings() {
+    let (url, server) = serve(|mut ws| async move {
+        let mut last = Vec::new();
+        for _ in 0..3 {
+            match ws.next().await {
+                Some(Ok(Message::Ping(p))) => last = p,
+                other => panic!("expected a ping, got {other:?}"),
+            }
+        }
+        ws.send(Message::Pong(last)).await.unwrap();
+        ws.send(Message::Text("done".into())).await.unwrap();
+        let _ = ws.next().await;
+    }).await;
+    let opts = WsOptions::new().ping_interval(Duration::from_millis(10));
+    let mut ws = WsConnection::connect_with(&url, &opts).await.unwrap();
+
+    let first = tokio::time::timeout(Duration::from_secs(5), ws.next()).await.unwrap();
+    assert_eq!(first.unwrap().unwrap(), WsMessage::Text("done".into()));
+    ws.send(WsMessage::Close(None)).await.unwrap();
+    server.await.unwrap();
+}
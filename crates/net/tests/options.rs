@@ -0,0 +1,33 @@
+//! URL building, including the browser's header → query fallback.
+
+use everywhere_net::WsOptions;
+
+#[test]
+fn query_keeps_existing_parameters() {
+    let opts = WsOptions::new().query("token", "a&b");
+    let url = opts.url("wss://example.com/ws?v=1").unwrap();
+    assert_eq!(url.as_str(), "wss://example.com/ws?v=1&token=a%26b");
+}
+
+#[test]
+fn no_query_leaves_url_alone() {
+    let url = WsOptions::new().url("wss://example.com/ws").unwrap();
+    assert_eq!(url.as_str(), "wss://example.com/ws");
+}
+
+#[test]
+fn browser_folds_headers_into_query() {
+    let opts = WsOptions::new()
+        .query("room", "1")
+        .bearer("tok")
+        .header("X-Api-Key", "k")
+        .header("Cookie", "a=1")
+        .header("Origin", "https://app.example");
+    let url = opts.browser_url("wss://example.com/ws").unwrap();
+    assert_eq!(url.as_str(), "wss://example.com/ws?room=1&access_token=tok&X-Api-Key=k");
+}
+
+#[test]
+fn bad_url_is_an_error() {
+    assert!(WsOptions::new().url("not a url").is_err());
+}
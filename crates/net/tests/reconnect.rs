@@ -0,0 +1,145 @@
+//! `ReconnectingWs` against a local server that kills connections.
+#![cfg(all(not(target_arch = "wasm32"), any(feature = "native", feature = "wasi")))]
+
+mod common;
+
+use async_tungstenite::tungstenite::Message;
+use common::{serve_each, ServerWs};
+use everywhere_net::{prelude::*, DropPolicy, ReconnectingWs, WsState};
+use futures_util::Stream;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use everywhere_runtime::time::{sleep, timeout};
+use everywhere_test::cross_test;
+
+const MS: Duration = Duration::from_millis(1);
+
+type Log = Arc<Mutex<Vec<(usize, String)>>>;
+
+/// Record `(connection, text)` for every text frame received.
+async fn record(n: usize, ws: &mut ServerWs, log: &Log, count: usize) {
+    for _ in 0..count {
+        match ws.next().await {
+            Some(Ok(Message::Text(t))) => log.lock().unwrap().push((n, t)),
+            _ => return,
+        }
+    }
+}
+
+/// Wait (bounded) until `states` yields `want`.
+async fn reach(states: &mut (impl Stream<Item = WsState> + Unpin), want: WsState) {
+    timeout(Duration::from_secs(5), async {
+        while let Some(s) = states.next().await {
+            if s == want { return; }
+        }
+        panic!("state stream ended before {want:?}");
+    }).await.unwrap_or_else(|_| panic!("never reached {want:?}"));
+}
+
+/// The hook runs on every connection; the first one is killed after it.
+#[cross_test]
+async fn reconnects_and_replays_hook() {
+    let log = Log::default();
+    let l = log.clone();
+    let url = serve_each(move |n, mut ws| {
+        let log = l.clone();
+        async move {
+            record(n, &mut ws, &log, 1).await;
+            if n > 0 { let _ = ws.next().await; }   // later ones stay up
+        }
+    });
+
+    let ws = ReconnectingWs::builder(url)
+        .backoff(|_| 10 * MS)
+        .on_connect(|| vec![WsMessage::Text("sub".into())])
+        .connect();
+    let mut states = ws.states();
+
+    for want in [WsState::Connecting, WsState::Open, WsState::Backoff(1), WsState::Connecting, WsState::Open] {
+        assert_eq!(timeout(Duration::from_secs(5), states.next()).await.unwrap(), Some(want));
+    }
+    timeout(Duration::from_secs(5), async {
+        while log.lock().unwrap().len() < 2 { sleep(MS).await; }
+    }).await.unwrap();
+    assert_eq!(*log.lock().unwrap(), [(0, "sub".into()), (1, "sub".into())]);
+}
+
+/// Messages sent while down go out after the reconnect; a full buffer
+/// evicts the oldest.
+#[cross_test]
+async fn buffers_while_down_dropping_oldest() {
+    let log = Log::default();
+    let l = log.clone();
+    let url = serve_each(move |n, mut ws| {
+        let log = l.clone();
+        async move { if n > 0 { record(n, &mut ws, &log, 2).await; } }
+    });
+
+    let ws = ReconnectingWs::builder(url)
+        .backoff(|_| 200 * MS)
+        .buffer(2, DropPolicy::Oldest)
+        .connect();
+    let mut states = ws.states();
+    reach(&mut states, WsState::Backoff(1)).await;
+
+    assert_eq!(ws.send(WsMessage::Text("a".into())), None);
+    assert_eq!(ws.send(WsMessage::Text("b".into())), None);
+    assert_eq!(ws.send(WsMessage::Text("c".into())), Some(WsMessage::Text("a".into())));
+    assert_eq!(ws.queued(), 2);
+
+    timeout(Duration::from_secs(5), async {
+        while log.lock().unwrap().len() < 2 { sleep(MS).await; }
+    }).await.unwrap();
+    assert_eq!(*log.lock().unwrap(), [(1, "b".into()), (1, "c".into())]);
+    assert_eq!(ws.queued(), 0);
+}
+
+#[cross_test]
+async fn newest_policy_refuses_new_messages() {
+    let url = serve_each(|_, _| async {});
+    let ws = ReconnectingWs::builder(url)
+        .backoff(|_| Duration::from_secs(60))
+        .buffer(1, DropPolicy::Newest)
+        .connect();
+    reach(&mut ws.states(), WsState::Backoff(1)).await;
+
+    assert_eq!(ws.send(WsMessage::Text("keep".into())), None);
+    assert_eq!(ws.send(WsMessage::Text("nope".into())), Some(WsMessage::Text("nope".into())));
+}
+
+/// Back‑off counts failed attempts while the server is unreachable.
+#[cross_test]
+async fn backoff_counts_up_while_unreachable() {
+    let free = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("ws://{}", free.local_addr().unwrap());
+    drop(free);
+
+    let ws = ReconnectingWs::builder(url).backoff(|_| MS).connect();
+    let mut states = ws.states();
+    reach(&mut states, WsState::Backoff(3)).await;
+    assert_ne!(ws.state(), WsState::Open);
+}
+
+/// Inbound messages flow through; `close` ends both streams.
+#[cross_test]
+async fn receives_and_closes() {
+    let url = serve_each(|_, mut ws| async move {
+        ws.send(Message::Text("hello".into())).await.unwrap();
+        while let Some(Ok(m)) = ws.next().await {
+            if m.is_close() { break; }
+        }
+    });
+
+    let mut ws = ReconnectingWs::builder(url).connect();
+    let mut states = ws.states();
+    let first = timeout(Duration::from_secs(5), ws.next()).await.unwrap();
+    assert_eq!(first, Some(WsMessage::Text("hello".into())));
+
+    ws.close();
+    reach(&mut states, WsState::Closed).await;
+    assert_eq!(states.next().await, None);
+    assert_eq!(timeout(Duration::from_secs(5), ws.next()).await.unwrap(), None);
+    assert_eq!(ws.send(WsMessage::Text("late".into())), Some(WsMessage::Text("late".into())));
+}
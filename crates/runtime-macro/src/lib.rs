@@ -0,0 +1,148 @@
+//! `#[everywhere::main]` – turn an `async fn main` into the right entry
+//! point for the target it is compiled for.
+//!
+//! * native  → Tokio or smol runtime (`flavor`, `worker_threads` configurable)
+//! * browser → `#[wasm_bindgen(start)]` + `spawn_local`
+//! * WASI    → `async_std::task::block_on`
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Expr, ExprLit, ItemFn, Lit, MetaNameValue, Token,
+};
+
+use proc_macro_crate::{crate_name, FoundCrate};
+
+/*──────────────────────── argument parsing ───────────────────────*/
+
+enum Flavor { CurrentThread, MultiThread }
+
+struct Args {
+    flavor: Flavor,
+    worker_threads: Option<usize>,
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let metas: Punctuated<MetaNameValue, Token![,]> =
+            input.parse_terminated(MetaNameValue::parse, Token![,])?;
+
+        let mut args = Args { flavor: Flavor::MultiThread, worker_threads: None };
+        let mut flavor_span = None;
+        for m in &metas {
+            let Expr::Lit(ExprLit { lit, .. }) = &m.value else {
+                return Err(syn::Error::new(m.value.span(), "expected a literal"));
+            };
+            match (m.path.get_ident().map(|i| i.to_string()).as_deref(), lit) {
+                (Some("flavor"), Lit::Str(s)) => {
+                    args.flavor = match s.value().as_str() {
+                        "current_thread" => Flavor::CurrentThread,
+                        "multi_thread"   => Flavor::MultiThread,
+                        _ => return Err(syn::Error::new(
+                            s.span(), "`flavor` is \"current_thread\" or \"multi_thread\"")),
+                    };
+                    flavor_span = Some(s.span());
+                }
+                (Some("worker_threads"), Lit::Int(n)) => {
+                    let n: usize = n.base10_parse()?;
+                    if n == 0 {
+                        return Err(syn::Error::new(lit.span(), "`worker_threads` must be > 0"));
+                    }
+                    args.worker_threads = Some(n);
+                }
+                _ => return Err(syn::Error::new(
+                    m.span(), "expected `flavor = \"…\"` or `worker_threads = N`")),
+            }
+        }
+
+        if let (Flavor::CurrentThread, Some(_)) = (&args.flavor, args.worker_threads) {
+            return Err(syn::Error::new(
+                flavor_span.unwrap_or_else(Span::call_site),
+                "`worker_threads` needs `flavor = \"multi_thread\"`",
+            ));
+        }
+        Ok(args)
+    }
+}
+
+/*──────────────────────── the macro proper ──────────────────────*/
+
+#[proc_macro_attribute]
+pub fn main(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let Args { flavor, worker_threads } = parse_macro_input!(attr as Args);
+    let ItemFn { attrs, vis, sig, block } = parse_macro_input!(item as ItemFn);
+
+    if sig.asyncness.is_none() {
+        return syn::Error::new(sig.span(), "`main` needs an async fn")
+            .to_compile_error()
+            .into();
+    }
+    if !sig.inputs.is_empty() {
+        return syn::Error::new(sig.inputs.span(), "`main` takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    /*── runtime crate path ────────────────────────────────────────*/
+    let root: proc_macro2::TokenStream = match crate_name("everywhere-runtime") {
+        /* `Itself` only happens in the runtime's own tests / examples */
+        Ok(FoundCrate::Itself) => quote!(::everywhere_runtime),
+        Ok(FoundCrate::Name(name)) => {
+            let ident = syn::Ident::new(&name, Span::call_site());
+            quote!(::#ident)
+        }
+        Err(_) => match crate_name("everywhere") {
+            Ok(FoundCrate::Name(name)) => {
+                let ident = syn::Ident::new(&name, Span::call_site());
+                quote!(::#ident::runtime)
+            }
+            _ => quote!(::everywhere_runtime),
+        },
+    };
+
+    let ident  = &sig.ident;
+    let output = &sig.output;
+
+    /*── native: whichever backend the runtime crate was built with ─*/
+    let multi = matches!(flavor, Flavor::MultiThread);
+    let workers = match worker_threads {
+        Some(n) => quote!(::core::option::Option::Some(#n)),
+        None    => quote!(::core::option::Option::None),
+    };
+
+    quote! {
+        #[cfg(not(target_arch = "wasm32"))]
+        #(#attrs)*
+        #vis fn #ident() #output {
+            #root::__rt::enter(#multi, #workers, async #block)
+        }
+
+        #[cfg(target_os = "wasi")]
+        #(#attrs)*
+        #vis fn #ident() #output {
+            #root::__rt::async_std::task::block_on(async #block)
+        }
+
+        #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+        #[#root::__rt::wasm_bindgen::prelude::wasm_bindgen(
+            start, wasm_bindgen = #root::__rt::wasm_bindgen
+        )]
+        #(#attrs)*
+        pub fn __everywhere_start() {
+            #root::__rt::wasm_bindgen_futures::spawn_local(async {
+                #root::__rt::Report::report(async #block.await)
+            });
+        }
+
+        /* the start hook above is the real entry point */
+        #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+        #[allow(dead_code)]
+        #vis fn #ident() {}
+    }
+    .into()
+}
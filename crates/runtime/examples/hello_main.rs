@@ -0,0 +1,15 @@
+//! `#[main]` picks the entry point for the target:
+//! `cargo run -p everywhere-runtime --example hello_main`.
+
+use everywhere_runtime::{task, time};
+use std::time::Duration;
+
+#[everywhere_runtime::main(flavor = "current_thread")]
+async fn main() -> Result<(), String> {
+    let h = task::spawn(async {
+        time::sleep(Duration::from_millis(10)).await;
+        "hello from a task"
+    });
+    println!("{}", h.await.map_err(|e| e.to_string())?);
+    Ok(())
+}
@@ -1,42 +1,127 @@
 //! Runtime contract + tiny cross‑runtime helpers.
 #![allow(async_fn_in_trait)]
 
-use core::{future::Future, time::Duration};
+use crate::{clock::Instant, handle::JoinHandle};
+pub(crate) use crate::error::*;
+use core::{future::Future, ops::Deref, time::Duration};
+use futures_util::{Sink, Stream};
 
 /*───────────────────────────────────────────────────────*
  * 1.  Channel helpers                                   *
  *───────────────────────────────────────────────────────*/
 
-pub trait SendMessage<T> { fn try_send(&self, v: T) -> Result<(), ()>; }
+/// Sender half of [`Runtime::channel`]; unbounded, so only a dropped
+/// receiver makes a send fail.
+pub trait SendMessage<T> { fn try_send(&self, v: T) -> Result<(), SendError<T>>; }
 
 pub trait SenderExt<T>: SendMessage<T> {
+    /// Enqueue `v`; the error hands it back if the receiver is gone.
     #[inline]
-    fn send_owned(&self, v: T) { let _ = self.try_send(v); }
+    fn send_owned(&self, v: T) -> Result<(), SendError<T>> { self.try_send(v) }
 }
 impl<T, S: SendMessage<T>> SenderExt<T> for S {}
 
-/* unified ‘recv’ helper (works on every backend) */
+/* unified receiver API (works on every backend) ----- */
+
+/// Receiving half of [`Runtime::channel`] and [`Runtime::channel_bounded`];
+/// every receiver is also a `Stream<Item = T>`.
 pub trait ReceiverExt<T>: Sized {
+    /// Next value, or `None` once every sender is gone and the queue is empty.
     async fn recv(&mut self) -> Option<T>;
+
+    /// Take a queued value without waiting.
+    fn try_recv(&mut self) -> Result<T, TryRecvError>;
+
+    /// [`recv`](Self::recv), giving up after `d`.
+    async fn recv_timeout(&mut self, d: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_timeout_with::<crate::Rt>(d).await
+    }
+
+    /// [`recv_timeout`](Self::recv_timeout) measured on runtime `R`.
+    async fn recv_timeout_with<R: Runtime>(&mut self, d: Duration) -> Result<T, RecvTimeoutError> {
+        match crate::time::timeout_with::<R, _>(d, self.recv()).await {
+            Ok(Some(v)) => Ok(v),
+            Ok(None)    => Err(RecvTimeoutError::Closed),
+            Err(_)      => Err(RecvTimeoutError::Timeout),
+        }
+    }
 }
 
-/* Tokio receiver ------------------------------------ */
-#[cfg(all(feature = "native", not(target_arch = "wasm32")))]
-impl<T: Send + 'static> ReceiverExt<T>
-for tokio::sync::mpsc::UnboundedReceiver<T>
-{
-    async fn recv(&mut self) -> Option<T> { self.recv().await }
+/* bounded senders (back‑pressure) -------------------- */
+
+/// Sender half of [`Runtime::channel_bounded`].
+pub trait BoundedSendMessage<T> {
+    /// Wait for a free slot, then enqueue `v`.
+    /// The error hands `v` back if the receiver was dropped.
+    async fn send(&mut self, v: T) -> Result<(), SendError<T>>;
+
+    /// Enqueue `v` only if a slot is free *right now*.
+    fn try_send(&mut self, v: T) -> Result<(), TrySendError<T>>;
 }
 
-/* futures‑mpsc receiver (browser + WASI) ------------- */
-#[cfg(any(feature = "browser", feature = "wasi"))]
-impl<T: 'static> ReceiverExt<T>
-for futures_channel::mpsc::UnboundedReceiver<T>
-{
-    async fn recv(&mut self) -> Option<T> {
-        use futures_util::StreamExt;
-        self.next().await
-    }
+/*───────────────────────────────────────────────────────*
+ * 1b. One‑shot / broadcast / watch                      *
+ *───────────────────────────────────────────────────────*/
+
+/// Sender half of [`Runtime::oneshot`]; the receiver is a plain
+/// `Future<Output = Result<T, RecvError>>`.
+pub trait OneshotSend<T>: Sized {
+    /// Deliver `v`; the error hands it back if the receiver was dropped.
+    fn send(self, v: T) -> Result<(), SendError<T>>;
+}
+
+/// Sender half of [`Runtime::broadcast`].
+pub trait BroadcastSend<T> {
+    type Receiver: BroadcastRecv<T>;
+
+    /// Fan `v` out to every live receiver and return how many there are.
+    /// Fails, handing `v` back, if there are none (nothing is retained).
+    fn send(&self, v: T) -> Result<usize, SendError<T>>;
+
+    /// New receiver that sees values sent *after* this call.
+    fn subscribe(&self) -> Self::Receiver;
+
+    fn receiver_count(&self) -> usize;
+}
+
+/// Receiver half of [`Runtime::broadcast`].
+pub trait BroadcastRecv<T> {
+    async fn recv(&mut self) -> Result<T, BroadcastRecvError>;
+}
+
+/// Sender half of [`Runtime::watch`].
+pub trait WatchSend<T> {
+    type Receiver: WatchRecv<T>;
+
+    /// Replace the value and notify receivers.
+    /// Fails, handing `v` back, if no receiver is left (nothing is stored).
+    fn send(&self, v: T) -> Result<(), SendError<T>>;
+
+    /// Replace the value even without receivers; returns the old one.
+    fn send_replace(&self, v: T) -> T;
+
+    fn borrow(&self) -> impl Deref<Target = T> + '_;
+
+    /// New receiver that treats the current value as already seen.
+    fn subscribe(&self) -> Self::Receiver;
+
+    fn receiver_count(&self) -> usize;
+}
+
+/// Receiver half of [`Runtime::watch`].
+pub trait WatchRecv<T>: Clone {
+    /// Wait until a value this receiver has not seen yet is stored.
+    /// `Err` once the sender is gone and nothing new is pending.
+    async fn changed(&mut self) -> Result<(), RecvError>;
+
+    /// `Err` once the sender is gone.
+    fn has_changed(&self) -> Result<bool, RecvError>;
+
+    /// Peek at the latest value without marking it seen.
+    fn borrow(&self) -> impl Deref<Target = T> + '_;
+
+    /// Peek at the latest value and mark it seen.
+    fn borrow_and_update(&mut self) -> impl Deref<Target = T> + '_;
 }
 
 /*───────────────────────────────────────────────────────*
@@ -56,22 +141,69 @@ impl<T: ?Sized> MaybeSend for T {}
  *───────────────────────────────────────────────────────*/
 
 pub trait Runtime: 'static {
-    type Sender<T: 'static + MaybeSend>: SenderExt<T> + Clone + 'static;
-    type Receiver<T: 'static + MaybeSend>: 'static;
-    type JoinHandle: 'static;
+    type Sender<T: 'static + MaybeSend>:
+        SenderExt<T> + Sink<T, Error = Closed> + Clone + Unpin + MaybeSend + 'static;
+    type Receiver<T: 'static + MaybeSend>:
+        ReceiverExt<T> + Stream<Item = T> + Unpin + MaybeSend + 'static;
+    type BoundedSender<T: 'static + MaybeSend>: BoundedSendMessage<T> + Clone + MaybeSend + 'static;
+    type BoundedReceiver<T: 'static + MaybeSend>:
+        ReceiverExt<T> + Stream<Item = T> + Unpin + MaybeSend + 'static;
+    type OneshotSender<T: 'static + MaybeSend>: OneshotSend<T> + MaybeSend + 'static;
+    type OneshotReceiver<T: 'static + MaybeSend>:
+        Future<Output = Result<T, RecvError>> + Unpin + MaybeSend + 'static;
+    type BroadcastSender<T: 'static + MaybeSend + Clone>:
+        BroadcastSend<T, Receiver = Self::BroadcastReceiver<T>> + Clone + 'static;
+    type BroadcastReceiver<T: 'static + MaybeSend + Clone>: BroadcastRecv<T> + 'static;
+    type WatchSender<T: 'static + MaybeSend>:
+        WatchSend<T, Receiver = Self::WatchReceiver<T>> + 'static;
+    type WatchReceiver<T: 'static + MaybeSend>: WatchRecv<T> + 'static;
+
+    fn spawn<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static;
+
+    /// Spawn a `!Send` future on the current thread; on native this must
+    /// happen inside [`Runtime::run_local`].
+    fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static;
 
-    fn spawn<F>(fut: F) -> Self::JoinHandle
+    /// Run blocking or CPU‑heavy `f` off the executor where threads exist;
+    /// single‑threaded targets run it inline on the next poll.
+    fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
     where
-        F: Future<Output=()> + MaybeSend + 'static;
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static;
+
+    /// Drive `fut` in a context where [`Runtime::spawn_local`] works.
+    async fn run_local<F: Future>(fut: F) -> F::Output { fut.await }
 
     fn channel<T: 'static + MaybeSend>() -> (Self::Sender<T>, Self::Receiver<T>);
 
-    async fn sleep(d: Duration);
+    /// Channel holding at most `cap` queued values (`cap > 0`).
+    fn channel_bounded<T: 'static + MaybeSend>(cap: usize)
+        -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>);
 
-    async fn timeout<F, T, E>(d: Duration, fut: F)
-                              -> Result<Result<T, E>, ()>
-    where
-        F: Future<Output=Result<T, E>> + MaybeSend + 'static;
+    /// Single value, single use – request/response replies.
+    fn oneshot<T: 'static + MaybeSend>() -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>);
+
+    /// Fan‑out keeping the last `cap` values (rounded up to a power of
+    /// two); slow receivers get [`BroadcastRecvError::Lagged`].
+    fn broadcast<T: 'static + MaybeSend + Clone>(cap: usize)
+        -> (Self::BroadcastSender<T>, Self::BroadcastReceiver<T>);
+
+    /// “Latest value” cell; receivers only ever see the newest state.
+    fn watch<T: 'static + MaybeSend>(init: T) -> (Self::WatchSender<T>, Self::WatchReceiver<T>);
+
+    /// Future returned by [`Runtime::sleep`]; nameable so it can be stored.
+    type Sleep: Future<Output = ()> + MaybeSend + 'static;
+
+    fn sleep(d: Duration) -> Self::Sleep;
+
+    /// The clock `sleep` is measured against.
+    fn now() -> Instant { Instant::now() }
 
     async fn yield_now();
 }
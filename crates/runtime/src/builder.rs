@@ -0,0 +1,88 @@
+//! `TaskBuilder` – spawn with a name that shows up in panic reports.
+//!
+//! ```ignore
+//! let h = TaskBuilder::new().name("reader").spawn(async { read_loop().await });
+//! ```
+
+use crate::{api::{MaybeSend, Runtime}, handle::JoinHandle, metrics, panic, Rt};
+use core::future::Future;
+
+/// Configures a task before spawning it.
+///
+/// Every task spawned through a builder reports a panic to the
+/// [panic handler](crate::task::set_panic_handler) before its
+/// [`JoinHandle`] yields [`JoinError::Panicked`](crate::task::JoinError::Panicked).
+#[derive(Debug, Clone, Default)]
+#[must_use = "a builder does nothing until you spawn with it"]
+pub struct TaskBuilder {
+    name: Option<String>,
+}
+
+impl TaskBuilder {
+    pub fn new() -> Self { Self::default() }
+
+    /// Name used in panic reports.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Spawn `fut` on the crate‑level [`Rt`].
+    pub fn spawn<F>(self, fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
+    {
+        self.spawn_with::<Rt, F>(fut)
+    }
+
+    /// Spawn `fut` on any `R: Runtime`.
+    pub fn spawn_with<R, F>(self, fut: F) -> JoinHandle<F::Output>
+    where
+        R: Runtime,
+        F: Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
+    {
+        let name = self.name;
+        metrics::named(name.as_deref(), || R::spawn(panic::reporting(name.clone(), fut)))
+    }
+
+    /// Spawn a `!Send` future on this thread (see [`spawn_local`](crate::task::spawn_local)).
+    pub fn spawn_local<F>(self, fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        self.spawn_local_with::<Rt, F>(fut)
+    }
+
+    pub fn spawn_local_with<R, F>(self, fut: F) -> JoinHandle<F::Output>
+    where
+        R: Runtime,
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        let name = self.name;
+        metrics::named(name.as_deref(), || R::spawn_local(panic::reporting(name.clone(), fut)))
+    }
+
+    /// Run blocking `f` (see [`spawn_blocking`](crate::task::spawn_blocking)).
+    pub fn spawn_blocking<F, T>(self, f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        self.spawn_blocking_with::<Rt, F, T>(f)
+    }
+
+    pub fn spawn_blocking_with<R, F, T>(self, f: F) -> JoinHandle<T>
+    where
+        R: Runtime,
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        let name = self.name;
+        let report = name.clone();
+        metrics::named(name.as_deref(), || R::spawn_blocking(move || panic::reporting_blocking(report, f)))
+    }
+}
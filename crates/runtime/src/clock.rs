@@ -0,0 +1,88 @@
+//! Monotonic clock that reads the same on every target.
+//!
+//! * Native / WASI → `std::time::Instant`, measured from a process‑wide origin.
+//! * Browser Wasm  → `performance.now()` (window *and* worker scopes).
+
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    time::Duration,
+};
+
+/// Opaque point on the monotonic clock; only differences are meaningful.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(Duration);   /* offset from an unspecified origin */
+
+impl Instant {
+    pub fn now() -> Self { Self(since_origin()) }
+
+    /// Time passed since `self`.
+    pub fn elapsed(&self) -> Duration { Self::now().duration_since(*self) }
+
+    /// `self - earlier`, or zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    pub fn checked_add(&self, d: Duration) -> Option<Instant> {
+        self.0.checked_add(d).map(Self)
+    }
+
+    pub fn checked_sub(&self, d: Duration) -> Option<Instant> {
+        self.0.checked_sub(d).map(Self)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+    fn add(self, d: Duration) -> Instant {
+        self.checked_add(d).expect("overflow when adding duration to instant")
+    }
+}
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, d: Duration) { *self = *self + d; }
+}
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+    fn sub(self, d: Duration) -> Instant {
+        self.checked_sub(d).expect("overflow when subtracting duration from instant")
+    }
+}
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, d: Duration) { *self = *self - d; }
+}
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+    fn sub(self, earlier: Instant) -> Duration { self.duration_since(earlier) }
+}
+
+impl fmt::Debug for Instant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Instant").field(&self.0).finish()
+    }
+}
+
+/*──────────── per‑target source ───────────*/
+
+#[cfg(not(all(target_arch = "wasm32", feature = "browser")))]
+fn since_origin() -> Duration {
+    use std::{sync::OnceLock, time::Instant as StdInstant};
+    static ORIGIN: OnceLock<StdInstant> = OnceLock::new();
+    ORIGIN.get_or_init(StdInstant::now).elapsed()
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "browser"))]
+fn since_origin() -> Duration {
+    use wasm_bindgen::prelude::wasm_bindgen;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = performance, js_name = now)]
+        fn performance_now() -> f64;
+    }
+    Duration::from_secs_f64(performance_now().max(0.0) / 1_000.0)
+}
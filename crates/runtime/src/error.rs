@@ -0,0 +1,158 @@
+//! Channel errors shared by every backend.
+//!
+//! Every failed send hands the value back, and every type implements
+//! `std::error::Error`, so `?` works in `anyhow`‑style code.
+
+use core::fmt;
+
+/*──────────────────────── sending ─────────────────────────────*/
+
+/// The receiver is gone; the value that could not be delivered is inside.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> SendError<T> {
+    /// Recover the value that could not be sent.
+    pub fn into_inner(self) -> T { self.0 }
+}
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SendError(..)") }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("channel closed") }
+}
+
+impl<T> std::error::Error for SendError<T> {}
+
+/// Why a non‑blocking send on a bounded channel failed.
+///
+/// Both variants hand the value back so nothing is lost.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TrySendError<T> {
+    /// Every slot is taken – retry later or `send().await`.
+    Full(T),
+    /// The receiver is gone; the value can never be delivered.
+    Closed(T),
+}
+
+impl<T> TrySendError<T> {
+    pub fn is_full(&self) -> bool { matches!(self, Self::Full(_)) }
+    pub fn is_closed(&self) -> bool { matches!(self, Self::Closed(_)) }
+
+    /// Recover the value that could not be sent.
+    pub fn into_inner(self) -> T {
+        match self { Self::Full(v) | Self::Closed(v) => v }
+    }
+}
+
+impl<T> From<SendError<T>> for TrySendError<T> {
+    fn from(e: SendError<T>) -> Self { Self::Closed(e.0) }
+}
+
+impl<T> fmt::Debug for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_)   => f.write_str("Full(..)"),
+            Self::Closed(_) => f.write_str("Closed(..)"),
+        }
+    }
+}
+
+impl<T> fmt::Display for TrySendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_)   => f.write_str("channel full"),
+            Self::Closed(_) => f.write_str("channel closed"),
+        }
+    }
+}
+
+impl<T> std::error::Error for TrySendError<T> {}
+
+/// `Sink` error of a channel sender: the receiver is gone.
+///
+/// `Sink` cannot hand the item back, so this one carries no value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Closed;
+
+impl fmt::Display for Closed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("channel closed") }
+}
+
+impl std::error::Error for Closed {}
+
+/*──────────────────────── receiving ───────────────────────────*/
+
+/// Every sender is gone and nothing is left to receive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecvError;
+
+impl fmt::Display for RecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("channel closed") }
+}
+
+impl std::error::Error for RecvError {}
+
+/// Why [`ReceiverExt::try_recv`](crate::ReceiverExt::try_recv) returned nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// Nothing queued right now; senders are still alive.
+    Empty,
+    /// Every sender is gone and the queue is drained.
+    Closed,
+}
+
+impl fmt::Display for TryRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty  => f.write_str("channel empty"),
+            Self::Closed => f.write_str("channel closed"),
+        }
+    }
+}
+
+impl std::error::Error for TryRecvError {}
+
+/// Why [`ReceiverExt::recv_timeout`](crate::ReceiverExt::recv_timeout)
+/// returned nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecvTimeoutError {
+    /// No value arrived in time.
+    Timeout,
+    /// Every sender is gone and the queue is drained.
+    Closed,
+}
+
+impl fmt::Display for RecvTimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Timeout => f.write_str("timed out waiting on channel"),
+            Self::Closed  => f.write_str("channel closed"),
+        }
+    }
+}
+
+impl std::error::Error for RecvTimeoutError {}
+
+/// Why a broadcast receiver came back empty‑handed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastRecvError {
+    /// Every sender is gone and the backlog is drained.
+    Closed,
+    /// The receiver fell behind and this many values were overwritten;
+    /// the next `recv` yields the oldest value still buffered.
+    Lagged(u64),
+}
+
+impl fmt::Display for BroadcastRecvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed    => f.write_str("channel closed"),
+            Self::Lagged(n) => write!(f, "receiver lagged by {n} values"),
+        }
+    }
+}
+
+impl std::error::Error for BroadcastRecvError {}
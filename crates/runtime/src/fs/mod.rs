@@ -0,0 +1,157 @@
+//! Async filesystem access with one API on every backend.
+//!
+//! | backend | implementation                                  |
+//! |---------|-------------------------------------------------|
+//! | native  | `tokio::fs`                                     |
+//! | smol    | `async-fs` (std I/O on the `blocking` pool)     |
+//! | WASI    | `async_std::fs` (preopened directories only)    |
+//! | browser | –                                               |
+//!
+//! **Not in the browser.** The module does not exist in
+//! `wasm32-unknown-unknown` builds with the `browser` feature, so code
+//! that needs files fails to *compile* there rather than failing at run
+//! time. Gate such code on `not(target_arch = "wasm32")` or on the
+//! backend feature. (An OPFS backend could fill the gap later.)
+//!
+//! [`File`] implements the `futures` `AsyncRead` / `AsyncWrite` traits;
+//! bring [`AsyncReadExt`] / [`AsyncWriteExt`] into scope for
+//! `read_to_end`, `write_all` and friends.
+//!
+//! ```ignore
+//! fs::create_dir_all("cache").await?;
+//! fs::write("cache/note.ogg", &bytes).await?;
+//! let back = fs::read("cache/note.ogg").await?;
+//! ```
+
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_util::io::{AsyncRead, AsyncWrite};
+use std::{
+    ffi::OsString,
+    fs::Metadata,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub use futures_util::io::{AsyncReadExt, AsyncWriteExt};
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "smol", not(target_arch = "wasm32")))] {
+        #[path = "smol.rs"]
+        mod imp;
+    } else if #[cfg(all(feature = "native", not(target_arch = "wasm32")))] {
+        #[path = "native.rs"]
+        mod imp;
+    } else {
+        #[path = "wasi.rs"]
+        mod imp;
+    }
+}
+
+/*──────────────────────── whole‑file helpers ─────────────────*/
+
+/// Read the whole file into memory.
+pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> { imp::read(path.as_ref()).await }
+
+/// Read the whole file as UTF‑8.
+pub async fn read_to_string(path: impl AsRef<Path>) -> io::Result<String> {
+    imp::read_to_string(path.as_ref()).await
+}
+
+/// Create or truncate `path` and write `contents` to it.
+pub async fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    imp::write(path.as_ref(), contents.as_ref()).await
+}
+
+/// Create `path` and every missing parent directory.
+pub async fn create_dir_all(path: impl AsRef<Path>) -> io::Result<()> {
+    imp::create_dir_all(path.as_ref()).await
+}
+
+pub async fn remove_file(path: impl AsRef<Path>) -> io::Result<()> {
+    imp::remove_file(path.as_ref()).await
+}
+
+pub async fn metadata(path: impl AsRef<Path>) -> io::Result<Metadata> {
+    imp::metadata(path.as_ref()).await
+}
+
+/*──────────────────────── directories ───────────────────────*/
+
+/// Entries of the directory at `path`, in no particular order.
+pub async fn read_dir(path: impl AsRef<Path>) -> io::Result<ReadDir> {
+    imp::read_dir(path.as_ref()).await.map(ReadDir)
+}
+
+/// Returned by [`read_dir`].
+pub struct ReadDir(imp::ReadDir);
+
+impl ReadDir {
+    /// The next entry, or `None` once the directory is exhausted.
+    pub async fn next_entry(&mut self) -> io::Result<Option<DirEntry>> {
+        Ok(imp::next_entry(&mut self.0).await?.map(|path| DirEntry { path }))
+    }
+}
+
+impl core::fmt::Debug for ReadDir {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { f.write_str("ReadDir { .. }") }
+}
+
+/// One entry yielded by [`ReadDir::next_entry`].
+#[derive(Debug, Clone)]
+pub struct DirEntry {
+    path: PathBuf,
+}
+
+impl DirEntry {
+    /// Full path: the directory passed to [`read_dir`] joined with the name.
+    pub fn path(&self) -> PathBuf { self.path.clone() }
+
+    pub fn file_name(&self) -> OsString { self.path.file_name().unwrap_or_default().to_owned() }
+
+    pub async fn metadata(&self) -> io::Result<Metadata> { imp::metadata(&self.path).await }
+}
+
+/*──────────────────────── streaming file ────────────────────*/
+
+/// An open file; read and write it through `AsyncRead` / `AsyncWrite`.
+///
+/// Call `close().await` (or `flush`) after writing: some backends
+/// finish writes in the background and report errors only then.
+pub struct File(imp::File);
+
+impl File {
+    /// Open an existing file for reading.
+    pub async fn open(path: impl AsRef<Path>) -> io::Result<File> {
+        imp::open(path.as_ref()).await.map(File)
+    }
+
+    /// Create or truncate a file for writing.
+    pub async fn create(path: impl AsRef<Path>) -> io::Result<File> {
+        imp::create(path.as_ref()).await.map(File)
+    }
+}
+
+impl AsyncRead for File {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_close(cx)
+    }
+}
+
+impl core::fmt::Debug for File {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result { f.write_str("File { .. }") }
+}
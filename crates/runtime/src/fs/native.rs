@@ -0,0 +1,53 @@
+//! `tokio::fs`, with `File` adapted to the `futures` I/O traits.
+
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures_util::io::{AsyncRead, AsyncWrite};
+use std::{
+    fs::Metadata,
+    io,
+    path::{Path, PathBuf},
+};
+use tokio::{fs, io::ReadBuf};
+
+pub async fn read(p: &Path) -> io::Result<Vec<u8>> { fs::read(p).await }
+pub async fn read_to_string(p: &Path) -> io::Result<String> { fs::read_to_string(p).await }
+pub async fn write(p: &Path, contents: &[u8]) -> io::Result<()> { fs::write(p, contents).await }
+pub async fn create_dir_all(p: &Path) -> io::Result<()> { fs::create_dir_all(p).await }
+pub async fn remove_file(p: &Path) -> io::Result<()> { fs::remove_file(p).await }
+pub async fn metadata(p: &Path) -> io::Result<Metadata> { fs::metadata(p).await }
+
+pub type ReadDir = fs::ReadDir;
+
+pub async fn read_dir(p: &Path) -> io::Result<ReadDir> { fs::read_dir(p).await }
+
+pub async fn next_entry(rd: &mut ReadDir) -> io::Result<Option<PathBuf>> {
+    Ok(rd.next_entry().await?.map(|e| e.path()))
+}
+
+pub struct File(fs::File);
+
+pub async fn open(p: &Path) -> io::Result<File> { fs::File::open(p).await.map(File) }
+pub async fn create(p: &Path) -> io::Result<File> { fs::File::create(p).await.map(File) }
+
+impl AsyncRead for File {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let mut buf = ReadBuf::new(buf);
+        ready!(tokio::io::AsyncRead::poll_read(Pin::new(&mut self.0), cx, &mut buf))?;
+        Poll::Ready(Ok(buf.filled().len()))
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        tokio::io::AsyncWrite::poll_write(Pin::new(&mut self.0), cx, buf)
+    }
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        tokio::io::AsyncWrite::poll_flush(Pin::new(&mut self.0), cx)
+    }
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        tokio::io::AsyncWrite::poll_shutdown(Pin::new(&mut self.0), cx)
+    }
+}
@@ -0,0 +1,29 @@
+//! `async-fs`: std I/O moved onto the `blocking` pool.
+
+use async_fs as fs;
+use futures_util::StreamExt;
+use std::{
+    fs::Metadata,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub async fn read(p: &Path) -> io::Result<Vec<u8>> { fs::read(p).await }
+pub async fn read_to_string(p: &Path) -> io::Result<String> { fs::read_to_string(p).await }
+pub async fn write(p: &Path, contents: &[u8]) -> io::Result<()> { fs::write(p, contents).await }
+pub async fn create_dir_all(p: &Path) -> io::Result<()> { fs::create_dir_all(p).await }
+pub async fn remove_file(p: &Path) -> io::Result<()> { fs::remove_file(p).await }
+pub async fn metadata(p: &Path) -> io::Result<Metadata> { fs::metadata(p).await }
+
+pub type ReadDir = fs::ReadDir;
+
+pub async fn read_dir(p: &Path) -> io::Result<ReadDir> { fs::read_dir(p).await }
+
+pub async fn next_entry(rd: &mut ReadDir) -> io::Result<Option<PathBuf>> {
+    rd.next().await.transpose().map(|e| e.map(|e| e.path()))
+}
+
+pub type File = fs::File;
+
+pub async fn open(p: &Path) -> io::Result<File> { fs::File::open(p).await }
+pub async fn create(p: &Path) -> io::Result<File> { fs::File::create(p).await }
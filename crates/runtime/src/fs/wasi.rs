@@ -0,0 +1,57 @@
+//! `async_std::fs`; WASI only sees directories the host preopened.
+
+use async_std::fs;
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures_util::{
+    io::{AsyncRead, AsyncWrite},
+    StreamExt,
+};
+use std::{
+    fs::Metadata,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub async fn read(p: &Path) -> io::Result<Vec<u8>> { fs::read(p).await }
+pub async fn read_to_string(p: &Path) -> io::Result<String> { fs::read_to_string(p).await }
+pub async fn write(p: &Path, contents: &[u8]) -> io::Result<()> { fs::write(p, contents).await }
+pub async fn create_dir_all(p: &Path) -> io::Result<()> { fs::create_dir_all(p).await }
+pub async fn remove_file(p: &Path) -> io::Result<()> { fs::remove_file(p).await }
+pub async fn metadata(p: &Path) -> io::Result<Metadata> { fs::metadata(p).await }
+
+pub type ReadDir = fs::ReadDir;
+
+pub async fn read_dir(p: &Path) -> io::Result<ReadDir> { fs::read_dir(p).await }
+
+pub async fn next_entry(rd: &mut ReadDir) -> io::Result<Option<PathBuf>> {
+    rd.next().await.transpose().map(|e| e.map(|e| e.path().into()))
+}
+
+/// `async_std::fs::File` whose `close` flushes first (upstream's does not,
+/// dropping the last buffered write).
+pub struct File(fs::File);
+
+pub async fn open(p: &Path) -> io::Result<File> { fs::File::open(p).await.map(File) }
+pub async fn create(p: &Path) -> io::Result<File> { fs::File::create(p).await.map(File) }
+
+impl AsyncRead for File {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for File {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(Pin::new(&mut self.0).poll_flush(cx))?;
+        Pin::new(&mut self.0).poll_close(cx)
+    }
+}
@@ -0,0 +1,120 @@
+//! Structured concurrency – a set of child tasks that live and die together.
+//!
+//! * children are spawned through the normal [`Runtime::spawn`];
+//! * results come back in **completion order** (`join_next`) or all at
+//!   once (`join_all`);
+//! * dropping the group aborts whatever is still running;
+//! * in fail‑fast mode the first failing child aborts its siblings.
+
+use crate::{api::Runtime, handle::{JoinError, JoinHandle}, Rt};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+
+/// Owner of a dynamic set of spawned children, all yielding `T`.
+pub struct TaskGroup<T> {
+    set: FuturesUnordered<JoinHandle<T>>,
+    fail_fast: Option<fn(&T) -> bool>,
+}
+
+/// Tokio‑flavoured name for the same thing.
+pub type JoinSet<T> = TaskGroup<T>;
+
+impl<T> Default for TaskGroup<T> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T> TaskGroup<T> {
+    pub fn new() -> Self {
+        Self { set: FuturesUnordered::new(), fail_fast: None }
+    }
+
+    /// Abort every remaining child once one panics or `failed(&output)`
+    /// holds for its output.
+    pub fn fail_fast_when(mut self, failed: fn(&T) -> bool) -> Self {
+        self.fail_fast = Some(failed);
+        self
+    }
+
+    /// Number of children not yet joined.
+    pub fn len(&self) -> usize { self.set.len() }
+    pub fn is_empty(&self) -> bool { self.set.is_empty() }
+
+    /// Adopt an already spawned task.
+    pub fn insert(&mut self, handle: JoinHandle<T>) { self.set.push(handle); }
+
+    /// Ask every remaining child to stop; they still show up in
+    /// `join_next` as [`JoinError::Cancelled`].
+    pub fn abort_all(&self) {
+        for h in self.set.iter() { h.abort(); }
+    }
+
+    /// Next child to finish, or `None` once the group is empty.
+    pub async fn join_next(&mut self) -> Option<Result<T, JoinError>> {
+        let out = self.set.next().await?;
+        if let Some(failed) = self.fail_fast {
+            let failure = match &out {
+                Ok(v)  => failed(v),
+                Err(e) => e.is_panic(),
+            };
+            if failure { self.abort_all(); }
+        }
+        Some(out)
+    }
+
+    /// Drain the group; results are in completion order.
+    pub async fn join_all(mut self) -> Vec<Result<T, JoinError>> {
+        let mut out = Vec::with_capacity(self.len());
+        while let Some(r) = self.join_next().await { out.push(r); }
+        out
+    }
+}
+
+impl<T, E> TaskGroup<Result<T, E>> {
+    /// Abort the siblings as soon as any child returns `Err` or panics.
+    pub fn fail_fast(self) -> Self { self.fail_fast_when(Result::is_err) }
+}
+
+/*──────────────────────── spawn ───────────────────────────────*/
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + 'static> TaskGroup<T> {
+    /// Spawn a child on the crate‑level [`Rt`].
+    pub fn spawn<F>(&mut self, fut: F)
+    where
+        F: core::future::Future<Output = T> + Send + 'static,
+    {
+        self.insert(Rt::spawn(fut));
+    }
+
+    /// Spawn a child on any `R: Runtime`.
+    pub fn spawn_with<R, F>(&mut self, fut: F)
+    where
+        R: Runtime,
+        F: core::future::Future<Output = T> + Send + 'static,
+    {
+        self.insert(R::spawn(fut));
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: 'static> TaskGroup<T> {
+    /// Spawn a child on the crate‑level [`Rt`].
+    pub fn spawn<F>(&mut self, fut: F)
+    where
+        F: core::future::Future<Output = T> + 'static,
+    {
+        self.insert(Rt::spawn(fut));
+    }
+
+    /// Spawn a child on any `R: Runtime`.
+    pub fn spawn_with<R, F>(&mut self, fut: F)
+    where
+        R: Runtime,
+        F: core::future::Future<Output = T> + 'static,
+    {
+        self.insert(R::spawn(fut));
+    }
+}
+
+impl<T> Drop for TaskGroup<T> {
+    fn drop(&mut self) { self.abort_all(); }
+}
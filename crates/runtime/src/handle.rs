@@ -0,0 +1,153 @@
+//! Crate‑owned task handle – awaitable and abortable on every backend.
+//!
+//! * Tokio → thin wrapper over `tokio::task::JoinHandle`.
+//! * everything else → the task is wrapped in an [`Abortable`], has its
+//!   panics caught, and ships its output back through a one‑shot.
+
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+use {
+    core::sync::atomic::{AtomicBool, Ordering},
+    futures_channel::oneshot,
+    futures_util::future::{AbortHandle, Abortable},
+    std::sync::Arc,
+};
+
+/*──────────────────────── error ──────────────────────────────*/
+
+/// Why awaiting a [`JoinHandle`] produced no value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinError {
+    /// [`JoinHandle::abort`] was called (or the runtime shut down).
+    Cancelled,
+    /// The task panicked before finishing.
+    Panicked,
+}
+
+impl JoinError {
+    pub fn is_cancelled(&self) -> bool { matches!(self, Self::Cancelled) }
+    pub fn is_panic(&self) -> bool { matches!(self, Self::Panicked) }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cancelled => f.write_str("task was cancelled"),
+            Self::Panicked  => f.write_str("task panicked"),
+        }
+    }
+}
+
+impl std::error::Error for JoinError {}
+
+/*──────────────────────── handle ─────────────────────────────*/
+
+/// Owned permission to await (or abort) a spawned task.
+///
+/// Dropping the handle detaches the task; it keeps running.
+pub struct JoinHandle<T> {
+    inner: Inner<T>,
+}
+
+enum Inner<T> {
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    Tokio(tokio::task::JoinHandle<T>),
+    #[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+    Remote {
+        rx: oneshot::Receiver<Result<T, JoinError>>,
+        abort: AbortHandle,
+        done: Arc<AtomicBool>,
+    },
+}
+
+/// Flags the task as finished however it ends (value, abort or panic).
+#[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+struct Finished(Arc<AtomicBool>);
+
+#[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+impl Drop for Finished {
+    fn drop(&mut self) { self.0.store(true, Ordering::Release); }
+}
+
+impl<T> JoinHandle<T> {
+    #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+    pub(crate) fn tokio(h: tokio::task::JoinHandle<T>) -> Self {
+        Self { inner: Inner::Tokio(h) }
+    }
+
+    /// Wrap `fut` for an executor without native handles; spawn the
+    /// returned future and give the handle to the caller.
+    #[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+    pub(crate) fn remote<F>(fut: F) -> (impl Future<Output = ()>, Self)
+    where
+        F: Future<Output = T>,
+    {
+        let (tx, rx) = oneshot::channel();
+        let (abort, reg) = AbortHandle::new_pair();
+        let done = Arc::new(AtomicBool::new(false));
+
+        let finished = Finished(done.clone());
+        let task = async move {
+            let _finished = finished;
+            // A task the executor drops without running to the end
+            // (shutdown) never sends → `JoinError::Cancelled`.
+            let out = match crate::panic::catch_unwind(Abortable::new(fut, reg)).await {
+                Ok(Ok(v))  => Ok(v),
+                Ok(Err(_)) => Err(JoinError::Cancelled),
+                Err(_)     => Err(JoinError::Panicked),
+            };
+            let _ = tx.send(out);
+        };
+        (task, Self { inner: Inner::Remote { rx, abort, done } })
+    }
+
+    /// Ask the task to stop at its next `.await`; awaiting the handle
+    /// then yields [`JoinError::Cancelled`].
+    pub fn abort(&self) {
+        match &self.inner {
+            #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+            Inner::Tokio(h) => h.abort(),
+            #[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+            Inner::Remote { abort, .. } => abort.abort(),
+        }
+    }
+
+    /// `true` once the task has stopped running, for whatever reason.
+    pub fn is_finished(&self) -> bool {
+        match &self.inner {
+            #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+            Inner::Tokio(h) => h.is_finished(),
+            #[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+            Inner::Remote { done, .. } => done.load(Ordering::Acquire),
+        }
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.get_mut().inner {
+            #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
+            Inner::Tokio(h) => Pin::new(h).poll(cx).map_err(|e| {
+                if e.is_cancelled() { JoinError::Cancelled } else { JoinError::Panicked }
+            }),
+            #[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+            Inner::Remote { rx, .. } => Pin::new(rx).poll(cx)
+                .map(|out| out.unwrap_or(Err(JoinError::Cancelled))),
+        }
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
@@ -0,0 +1,92 @@
+//! Spawn hooks: carry context (a `tracing::Span`, a request ID, …) from
+//! the spawning task into every task it spawns.
+//!
+//! A hook runs in the spawner and may return a [`SpawnContext`]; the new
+//! task then polls *inside* that context. With the `tracing` feature the
+//! current span is propagated this way without registering anything.
+
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll},
+};
+use crate::metrics::TaskStat;
+use std::sync::RwLock;
+
+/// Wraps each poll of a spawned task: call the argument exactly once,
+/// with whatever context should be active around it.
+#[cfg(not(target_arch = "wasm32"))]
+pub type SpawnContext = Box<dyn FnMut(&mut dyn FnMut()) + Send>;
+#[cfg(target_arch = "wasm32")]
+pub type SpawnContext = Box<dyn FnMut(&mut dyn FnMut())>;
+
+type Capture = Box<dyn Fn() -> Option<SpawnContext> + Send + Sync>;
+
+static HOOKS: RwLock<Vec<Capture>> = RwLock::new(Vec::new());
+static ANY_HOOKS: AtomicBool = AtomicBool::new(false);
+
+/// Run `capture` in the spawning task on every `spawn` / `spawn_local`.
+///
+/// Hooks are process‑wide and cannot be removed; register them once at
+/// start‑up.
+pub fn add_spawn_hook(capture: impl Fn() -> Option<SpawnContext> + Send + Sync + 'static) {
+    HOOKS.write().unwrap_or_else(|e| e.into_inner()).push(Box::new(capture));
+    ANY_HOOKS.store(true, Ordering::Release);
+}
+
+/// Capture the spawner's context for `fut`; used by every backend.
+pub(crate) fn wrap<F: Future>(fut: F) -> Hooked<F> {
+    let mut ctx = Vec::new();
+
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        if !span.is_none() {
+            ctx.push(Box::new(move |poll: &mut dyn FnMut()| span.in_scope(poll)) as SpawnContext);
+        }
+    }
+
+    if ANY_HOOKS.load(Ordering::Acquire) {
+        let hooks = HOOKS.read().unwrap_or_else(|e| e.into_inner());
+        ctx.extend(hooks.iter().filter_map(|capture| capture()));
+    }
+    Hooked { fut, ctx, stat: TaskStat::spawned() }
+}
+
+/*──────────────────────── future ──────────────────────────────*/
+
+/// A spawned future plus the contexts captured for it.
+pub(crate) struct Hooked<F> {
+    fut: F,
+    ctx: Vec<SpawnContext>,
+    stat: TaskStat,
+}
+
+impl<F: Future> Future for Hooked<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        // SAFETY: `fut` is structurally pinned and never moved; the rest is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        let mut fut = unsafe { Pin::new_unchecked(&mut this.fut) };
+
+        let out = if this.ctx.is_empty() {
+            fut.poll(cx)
+        } else {
+            let mut out = Poll::Pending;
+            enter(&mut this.ctx, &mut || out = fut.as_mut().poll(cx));
+            out
+        };
+        if out.is_ready() { this.stat.completed(); }
+        out
+    }
+}
+
+/// Nest the contexts outermost‑first, with `poll` at the core.
+fn enter(ctx: &mut [SpawnContext], poll: &mut dyn FnMut()) {
+    match ctx.split_first_mut() {
+        None                => poll(),
+        Some((outer, rest)) => outer(&mut || enter(rest, poll)),
+    }
+}
@@ -0,0 +1,122 @@
+//! Drift‑free periodic ticks.
+//!
+//! * ticks are scheduled against absolute deadlines (`start + n·period`),
+//!   so slow consumers do not accumulate drift;
+//! * the first tick completes at `start` (immediately for [`interval`]);
+//! * what happens after a stall is chosen by [`MissedTickBehavior`].
+//!
+//! [`interval`]: crate::time::interval
+
+use crate::{api::Runtime, clock::Instant, metrics, Rt};
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use futures_util::{future::poll_fn, Stream};
+
+/// Lateness below this is treated as timer jitter, not a missed tick.
+const JITTER: Duration = Duration::from_millis(5);
+
+/// How an [`Interval`] catches up after ticks were missed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Fire the missed ticks back to back, then resume the original
+    /// schedule.
+    #[default]
+    Burst,
+    /// Restart the schedule one `period` after the late tick.
+    Delay,
+    /// Drop the missed ticks; fire next on the original schedule.
+    Skip,
+}
+
+impl MissedTickBehavior {
+    /// Deadline following a tick due at `due` but observed at `now`.
+    fn next(self, due: Instant, now: Instant, period: Duration) -> Instant {
+        match self {
+            Self::Burst => due + period,
+            Self::Delay => now + period,
+            Self::Skip  => {
+                let late = (now - due).as_nanos() % period.as_nanos();
+                now + period - Duration::from_nanos(late as u64)
+            }
+        }
+    }
+}
+
+/// Stream of tick instants, one per `period`.
+pub struct Interval<R: Runtime = Rt> {
+    deadline: Instant,
+    period: Duration,
+    missed: MissedTickBehavior,
+    sleep: Option<Pin<Box<metrics::Sleep<R>>>>,
+}
+
+impl<R: Runtime> Interval<R> {
+    pub(crate) fn new(start: Instant, period: Duration) -> Self {
+        assert!(!period.is_zero(), "`period` must be non-zero");
+        Self { deadline: start, period, missed: MissedTickBehavior::default(), sleep: None }
+    }
+
+    /// Wait for the next tick; returns the instant it was *scheduled* for.
+    pub async fn tick(&mut self) -> Instant {
+        poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Instant> {
+        loop {
+            let now = R::now();
+            if now < self.deadline {
+                let sleep = self.sleep.get_or_insert_with(|| Box::pin(metrics::sleep::<R>(self.deadline - now)));
+                if sleep.as_mut().poll(cx).is_pending() { return Poll::Pending; }
+                /* re‑check: some timers fire a little early */
+                self.sleep = None;
+                continue;
+            }
+
+            let due = self.deadline;
+            self.deadline = if now > due + JITTER {
+                self.missed.next(due, now, self.period)
+            } else {
+                due + self.period
+            };
+            self.sleep = None;
+            return Poll::Ready(due);
+        }
+    }
+
+    /// Restart the schedule so the next tick is one `period` from now.
+    pub fn reset(&mut self) {
+        self.deadline = R::now() + self.period;
+        self.sleep = None;
+    }
+
+    pub fn period(&self) -> Duration { self.period }
+
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior { self.missed }
+
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed = behavior;
+    }
+}
+
+impl<R: Runtime> Stream for Interval<R> {
+    type Item = Instant;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Instant>> {
+        self.get_mut().poll_tick(cx).map(Some)
+    }
+}
+
+impl<R: Runtime> fmt::Debug for Interval<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interval")
+            .field("deadline", &self.deadline)
+            .field("period", &self.period)
+            .field("missed_tick_behavior", &self.missed)
+            .finish()
+    }
+}
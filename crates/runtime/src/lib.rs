@@ -1,27 +1,87 @@
 //! **everywhere‑runtime**
 //!
-//! Portable async primitives on **Tokio** (native), **wasm‑bindgen**
-//! (browser) and **async‑std** (WASI).
+//! Portable async primitives on **Tokio** (native), **smol** (native,
+//! no Tokio), **wasm‑bindgen** (browser) and **async‑std** (WASI).
 
 #![allow(async_fn_in_trait)]
 #![cfg_attr(
     not(feature = "native"),
-    cfg_attr(not(feature = "browser"),
-        cfg_attr(not(feature = "wasi"), no_std))
+    cfg_attr(not(feature = "smol"),
+        cfg_attr(not(feature = "browser"),
+            cfg_attr(not(feature = "wasi"), no_std)))
 )]
 
 mod api;               /* contract & helpers */
+mod builder;           /* TaskBuilder */
+mod clock;             /* portable monotonic Instant */
+mod error;             /* channel errors */
+mod group;             /* TaskGroup / JoinSet */
+mod handle;            /* crate‑owned JoinHandle */
+mod hook;              /* spawn hooks (context propagation) */
+mod interval;          /* periodic tick stream */
+mod local;             /* task_local! / LocalKey */
+#[cfg(not(feature = "metrics"))]
+mod metrics;           /* no‑op counters */
+mod panic;             /* panic isolation & reports */
 mod rt;                /* concrete back‑ends */
+mod timeout;           /* Timeout<F> / Elapsed */
 
+#[cfg(not(all(feature = "browser", target_arch = "wasm32")))]
+pub mod fs;            /* async file I/O (not in the browser) */
+#[cfg(feature = "metrics")]
+pub mod metrics;       /* counters & snapshot()    */
+pub mod signal;        /* ctrl_c / shutdown        */
+pub mod sync;          /* cancellation & friends   */
 pub mod task;          /* spawn / channel façade   */
 pub mod time;          /* sleep  / timeout façade  */
 
 /*──────────────────────── re‑exports ───────────────────────*/
-pub use api::{Runtime as _RtTrait, SenderExt, ReceiverExt};
+pub use api::{
+    Runtime as _RtTrait, MaybeSend, SenderExt, ReceiverExt, BoundedSendMessage,
+    OneshotSend, BroadcastSend, BroadcastRecv, WatchSend, WatchRecv,
+};
+pub use error::{
+    BroadcastRecvError, Closed, RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError,
+};
+pub use group::{JoinSet, TaskGroup};
+pub use handle::{JoinError, JoinHandle};
 pub use rt::Rt;
+/// Run `fut` to completion from synchronous code (not in the browser,
+/// whose main thread cannot block).
+#[cfg(not(all(feature = "browser", target_arch = "wasm32")))]
+pub use rt::block_on;
+pub use everywhere_runtime_macro::main;
+#[cfg(feature = "mock")]
+pub use rt::MockRt;
+
+/* Plumbing for `#[main]`; not public API. */
+#[doc(hidden)]
+pub mod __rt {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use crate::rt::enter;
+    #[cfg(all(feature = "browser", target_arch = "wasm32"))]
+    pub use {wasm_bindgen, wasm_bindgen_futures};
+    #[cfg(all(feature = "wasi", target_arch = "wasm32"))]
+    pub use async_std;
+
+    /// What the browser entry point does with `main`'s return value.
+    pub trait Report { fn report(self); }
+
+    impl Report for () { fn report(self) {} }
+
+    impl<E: core::fmt::Debug> Report for Result<(), E> {
+        fn report(self) {
+            if let Err(e) = self { panic!("main returned an error: {e:?}") }
+        }
+    }
+}
 
 /* Common glob import */
 pub mod prelude {
-    pub use super::{Rt, SenderExt, ReceiverExt};
+    pub use super::{
+        Rt, SenderExt, ReceiverExt, BoundedSendMessage,
+        OneshotSend, BroadcastSend, BroadcastRecv, WatchSend, WatchRecv,
+    };
     pub use crate::api::Runtime;
+    pub use crate::time::FutureExt as _;
 }
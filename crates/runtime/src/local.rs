@@ -0,0 +1,172 @@
+//! Task‑local storage that works the same on every backend.
+//!
+//! A value is set for the extent of a future with [`LocalKey::scope`];
+//! on each poll it is swapped into a thread‑local slot and swapped back
+//! out afterwards, so it follows the task across worker threads.
+//!
+//! ```ignore
+//! everywhere_runtime::task_local! {
+//!     static REQUEST_ID: u64;
+//! }
+//!
+//! REQUEST_ID.scope(42, async {
+//!     assert_eq!(REQUEST_ID.get(), 42);
+//! }).await;
+//! ```
+
+use crate::api::MaybeSend;
+use core::{
+    cell::RefCell,
+    fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Declare one or more task‑local keys of type [`LocalKey`].
+#[macro_export]
+macro_rules! task_local {
+    () => {};
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::task::LocalKey<$t> = {
+            ::std::thread_local! {
+                static __SLOT: ::core::cell::RefCell<::core::option::Option<$t>> =
+                    const { ::core::cell::RefCell::new(::core::option::Option::None) };
+            }
+            $crate::task::LocalKey { slot: __SLOT }
+        };
+
+        $crate::task_local!($($rest)*);
+    };
+}
+
+/*──────────────────────── key ─────────────────────────────────*/
+
+/// Key created by [`task_local!`](crate::task_local).
+pub struct LocalKey<T: 'static> {
+    #[doc(hidden)]
+    pub slot: std::thread::LocalKey<RefCell<Option<T>>>,
+}
+
+impl<T: 'static> LocalKey<T> {
+    /// Run `fut` with this key set to `value`.
+    pub fn scope<F: Future>(&'static self, value: T, fut: F) -> TaskLocalFuture<T, F> {
+        TaskLocalFuture { key: self, value: Some(value), fut }
+    }
+
+    /// Run the closure `f` with this key set to `value`.
+    pub fn sync_scope<R>(&'static self, value: T, f: impl FnOnce() -> R) -> R {
+        let mut value = Some(value);
+        let _guard = self.enter(&mut value);
+        f()
+    }
+
+    /// Borrow the current value.
+    ///
+    /// # Panics
+    /// Outside of a [`scope`](Self::scope) for this key.
+    pub fn with<R>(&'static self, f: impl FnOnce(&T) -> R) -> R {
+        self.try_with(f).expect("task-local value not set")
+    }
+
+    /// Borrow the current value, if one is set.
+    pub fn try_with<R>(&'static self, f: impl FnOnce(&T) -> R) -> Result<R, AccessError> {
+        self.slot.with(|slot| match &*slot.borrow() {
+            Some(v) => Ok(f(v)),
+            None    => Err(AccessError(())),
+        })
+    }
+
+    /// Copy of the current value.
+    ///
+    /// # Panics
+    /// Outside of a [`scope`](Self::scope) for this key.
+    pub fn get(&'static self) -> T
+    where
+        T: Clone,
+    {
+        self.with(T::clone)
+    }
+
+    /// Let tasks spawned inside a scope of this key start in a scope of
+    /// their own, holding a clone of the spawner's value.
+    ///
+    /// Registers a process‑wide [spawn hook](crate::task::add_spawn_hook);
+    /// call it once per key.
+    pub fn inherit_on_spawn(&'static self)
+    where
+        T: Clone + MaybeSend,
+    {
+        crate::hook::add_spawn_hook(move || {
+            let mut value = Some(self.try_with(T::clone).ok()?);
+            Some(Box::new(move |poll: &mut dyn FnMut()| {
+                let _guard = self.enter(&mut value);
+                poll()
+            }))
+        });
+    }
+
+    /// Swap `value` into the slot until the guard drops (also on panic).
+    fn enter<'a>(&'static self, value: &'a mut Option<T>) -> Guard<'a, T> {
+        self.slot.with(|slot| mem::swap(&mut *slot.borrow_mut(), value));
+        Guard { key: self, value }
+    }
+}
+
+impl<T: 'static> fmt::Debug for LocalKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("LocalKey { .. }") }
+}
+
+struct Guard<'a, T: 'static> {
+    key: &'static LocalKey<T>,
+    value: &'a mut Option<T>,
+}
+
+impl<T: 'static> Drop for Guard<'_, T> {
+    fn drop(&mut self) {
+        self.key.slot.with(|slot| mem::swap(&mut *slot.borrow_mut(), self.value));
+    }
+}
+
+/*──────────────────────── error ───────────────────────────────*/
+
+/// The key was read outside of a scope that sets it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessError(());
+
+impl fmt::Display for AccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("task-local value not set") }
+}
+
+impl std::error::Error for AccessError {}
+
+/*──────────────────────── future ──────────────────────────────*/
+
+/// Future returned by [`LocalKey::scope`].
+#[must_use = "futures do nothing unless polled"]
+pub struct TaskLocalFuture<T: 'static, F> {
+    key: &'static LocalKey<T>,
+    value: Option<T>,
+    fut: F,
+}
+
+impl<T: 'static, F: Future> Future for TaskLocalFuture<T, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        // SAFETY: `fut` is never moved out of the pinned struct; `key`
+        // and `value` are not structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        let _guard = this.key.enter(&mut this.value);
+        unsafe { Pin::new_unchecked(&mut this.fut) }.poll(cx)
+    }
+}
+
+impl<T: 'static, F> fmt::Debug for TaskLocalFuture<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaskLocalFuture").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,527 @@
+//! Runtime counters, read through [`snapshot`] (feature `metrics`).
+//!
+//! * tasks spawned / alive / completed, grouped by
+//!   [`TaskBuilder::name`](crate::task::TaskBuilder::name);
+//! * queue depth of every live mpsc, broadcast and watch channel made
+//!   by this crate;
+//! * timers created / active / fired, plus a histogram of how late each
+//!   sleep woke up.
+//!
+//! The counters live in the crate, not in a backend, so the numbers mean
+//! the same thing natively and in the browser. Without the feature every
+//! hook below compiles to nothing.
+
+use crate::api::Runtime;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+#[cfg(feature = "metrics")]
+pub use on::*;
+#[cfg(feature = "metrics")]
+use on::{ChannelCell, TaskCell, TimerStat};
+
+/*──────────────────────── tasks ───────────────────────────────*/
+
+/// Per‑task bookkeeping carried by every spawned future.
+#[derive(Default)]
+pub(crate) struct TaskStat {
+    #[cfg(feature = "metrics")]
+    cell: Option<std::sync::Arc<TaskCell>>,
+    #[cfg(feature = "metrics")]
+    done: bool,
+}
+
+impl TaskStat {
+    /// Count a spawn under the name set by [`named`], if any.
+    pub(crate) fn spawned() -> Self {
+        #[cfg(feature = "metrics")]
+        return Self { cell: Some(on::task_spawned()), done: false };
+        #[cfg(not(feature = "metrics"))]
+        Self {}
+    }
+
+    pub(crate) fn completed(&mut self) {
+        #[cfg(feature = "metrics")]
+        if let (Some(cell), false) = (&self.cell, self.done) {
+            self.done = true;
+            cell.completed();
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for TaskStat {
+    fn drop(&mut self) {
+        if let Some(cell) = &self.cell { cell.dropped(); }
+    }
+}
+
+/// Run `spawn` with `name` attached to the task it creates.
+pub(crate) fn named<T>(name: Option<&str>, spawn: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "metrics")]
+    return on::named(name, spawn);
+    #[cfg(not(feature = "metrics"))]
+    { let _ = name; spawn() }
+}
+
+/*──────────────────────── channels ────────────────────────────*/
+
+/// Queue‑depth gauge shared by both halves of an mpsc channel.
+#[derive(Clone)]
+pub(crate) struct Depth {
+    #[cfg(feature = "metrics")]
+    cell: std::sync::Arc<ChannelCell>,
+}
+
+impl Depth {
+    /// Register a channel; `capacity` is `None` for unbounded ones.
+    pub(crate) fn new(capacity: Option<usize>) -> Self {
+        #[cfg(feature = "metrics")]
+        return Self { cell: on::channel_created(ChannelKind::Mpsc, capacity) };
+        #[cfg(not(feature = "metrics"))]
+        { let _ = capacity; Self {} }
+    }
+
+    /// Pass a send result through, counting a success.
+    #[inline]
+    pub(crate) fn sent<E>(&self, r: Result<(), E>) -> Result<(), E> {
+        #[cfg(feature = "metrics")]
+        if r.is_ok() { self.cell.add(1); self.cell.sent(); }
+        r
+    }
+
+    /// Pass a received value through, counting it.
+    #[inline]
+    pub(crate) fn recv<T>(&self, v: Option<T>) -> Option<T> {
+        #[cfg(feature = "metrics")]
+        if v.is_some() { self.cell.add(-1); }
+        v
+    }
+
+    #[inline]
+    pub(crate) fn try_recv<T, E>(&self, r: Result<T, E>) -> Result<T, E> {
+        #[cfg(feature = "metrics")]
+        if r.is_ok() { self.cell.add(-1); }
+        r
+    }
+
+    #[inline]
+    pub(crate) fn poll<T>(&self, p: Poll<Option<T>>) -> Poll<Option<T>> { p.map(|v| self.recv(v)) }
+}
+
+/// Backlog gauge of a broadcast or watch channel, held by its senders.
+///
+/// Every receiver keeps a [`Cursor`] into the count of sent values; the
+/// channel's depth is the longest backlog among them, capped at the
+/// capacity (1 for watch).
+#[derive(Clone)]
+pub(crate) struct Fanout {
+    #[cfg(feature = "metrics")]
+    cell: std::sync::Arc<ChannelCell>,
+}
+
+impl Fanout {
+    #[cfg(feature = "metrics")]
+    fn new(kind: ChannelKind, capacity: usize) -> Self {
+        Self { cell: on::channel_created(kind, Some(capacity)) }
+    }
+
+    pub(crate) fn broadcast(capacity: usize) -> Self {
+        #[cfg(feature = "metrics")]
+        return Self::new(ChannelKind::Broadcast, capacity);
+        #[cfg(not(feature = "metrics"))]
+        { let _ = capacity; Self {} }
+    }
+
+    pub(crate) fn watch() -> Self {
+        #[cfg(feature = "metrics")]
+        return Self::new(ChannelKind::Watch, 1);
+        #[cfg(not(feature = "metrics"))]
+        Self {}
+    }
+
+    /// Pass a send result through, counting a success.
+    #[inline]
+    pub(crate) fn sent<T, E>(&self, r: Result<T, E>) -> Result<T, E> {
+        #[cfg(feature = "metrics")]
+        if r.is_ok() { self.cell.sent(); }
+        r
+    }
+
+    /// Count a value stored unconditionally (watch `send_replace`).
+    #[inline]
+    pub(crate) fn stored(&self) {
+        #[cfg(feature = "metrics")]
+        self.cell.sent();
+    }
+
+    /// A receiver that has seen everything sent so far.
+    pub(crate) fn cursor(&self) -> Cursor {
+        #[cfg(feature = "metrics")]
+        return Cursor { pos: self.cell.cursor_at(self.cell.sent_count()), cell: self.cell.clone() };
+        #[cfg(not(feature = "metrics"))]
+        Cursor {}
+    }
+}
+
+/// One receiver's position in a [`Fanout`].
+pub(crate) struct Cursor {
+    #[cfg(feature = "metrics")]
+    cell: std::sync::Arc<ChannelCell>,
+    #[cfg(feature = "metrics")]
+    pos: std::sync::Arc<core::sync::atomic::AtomicU64>,
+}
+
+impl Cursor {
+    /// Pass a broadcast receive through: a value moves the cursor by
+    /// one, `Lagged(n)` skips `n`.
+    #[inline]
+    pub(crate) fn recv<T>(
+        &self,
+        r: Result<T, crate::api::BroadcastRecvError>,
+    ) -> Result<T, crate::api::BroadcastRecvError> {
+        #[cfg(feature = "metrics")]
+        {
+            use core::sync::atomic::Ordering::Relaxed;
+            match &r {
+                Ok(_) => { self.pos.fetch_add(1, Relaxed); }
+                Err(crate::api::BroadcastRecvError::Lagged(n)) => { self.pos.fetch_add(*n, Relaxed); }
+                Err(_) => {}
+            }
+        }
+        r
+    }
+
+    /// The receiver has seen the latest value (watch).
+    #[inline]
+    pub(crate) fn caught_up(&self) {
+        #[cfg(feature = "metrics")]
+        self.pos.store(self.cell.sent_count(), core::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// A second receiver at the same position (watch receivers clone).
+    pub(crate) fn fork(&self) -> Self {
+        #[cfg(feature = "metrics")]
+        return Self {
+            pos: self.cell.cursor_at(self.pos.load(core::sync::atomic::Ordering::Relaxed)),
+            cell: self.cell.clone(),
+        };
+        #[cfg(not(feature = "metrics"))]
+        Self {}
+    }
+}
+
+/*──────────────────────── timers ──────────────────────────────*/
+
+/// `R::sleep(d)`, counted as a timer; used for every sleep the crate makes.
+pub(crate) fn sleep<R: Runtime>(d: Duration) -> Sleep<R> {
+    Sleep {
+        inner: R::sleep(d),
+        #[cfg(feature = "metrics")]
+        stat: TimerStat::start(R::now(), d),
+    }
+}
+
+#[must_use = "futures do nothing unless polled"]
+pub(crate) struct Sleep<R: Runtime> {
+    inner: R::Sleep,
+    #[cfg(feature = "metrics")]
+    stat: TimerStat,
+}
+
+impl<R: Runtime> Future for Sleep<R> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // SAFETY: `inner` is structurally pinned and never moved; `stat` is not.
+        let this = unsafe { self.get_unchecked_mut() };
+        let ready = unsafe { Pin::new_unchecked(&mut this.inner) }.poll(cx);
+        #[cfg(feature = "metrics")]
+        if ready.is_ready() { this.stat.fired(R::now()); }
+        ready
+    }
+}
+
+/*──────────────────────── registry (feature `metrics`) ───────*/
+
+#[cfg(feature = "metrics")]
+mod on {
+    use crate::clock::Instant;
+    use core::{
+        cell::RefCell,
+        sync::atomic::{AtomicI64, AtomicU64, Ordering::Relaxed},
+        time::Duration,
+    };
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex, Weak},
+    };
+
+    /// Name under which tasks without one are counted.
+    pub const UNNAMED: &str = "<unnamed>";
+
+    /// Upper bounds of the overshoot histogram buckets; the last bucket
+    /// takes everything later than the final bound.
+    pub const OVERSHOOT_BOUNDS: [Duration; 7] = [
+        Duration::from_millis(1),
+        Duration::from_millis(2),
+        Duration::from_millis(5),
+        Duration::from_millis(10),
+        Duration::from_millis(20),
+        Duration::from_millis(50),
+        Duration::from_millis(100),
+    ];
+
+    static TASKS: Mutex<BTreeMap<String, Arc<TaskCell>>> = Mutex::new(BTreeMap::new());
+    static CHANNELS: Mutex<Vec<Weak<ChannelCell>>> = Mutex::new(Vec::new());
+
+    static TIMERS_CREATED: AtomicU64 = AtomicU64::new(0);
+    static TIMERS_ACTIVE: AtomicI64 = AtomicI64::new(0);
+    static TIMERS_FIRED: AtomicU64 = AtomicU64::new(0);
+    static OVERSHOOT: [AtomicU64; 8] = [const { AtomicU64::new(0) }; 8];
+    static OVERSHOOT_SUM_NS: AtomicU64 = AtomicU64::new(0);
+    static OVERSHOOT_MAX_NS: AtomicU64 = AtomicU64::new(0);
+
+    std::thread_local! {
+        static SPAWN_NAME: RefCell<Option<String>> = const { RefCell::new(None) };
+    }
+
+    fn lock<T>(m: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+        m.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /*──────────── snapshot types ───────────*/
+
+    /// Every counter at one point in time.
+    #[derive(Debug, Clone, Default)]
+    pub struct Snapshot {
+        /// One entry per task name, sorted by name.
+        pub tasks: Vec<TaskMetrics>,
+        /// One entry per live channel, oldest first.
+        pub channels: Vec<ChannelMetrics>,
+        pub timers: TimerMetrics,
+    }
+
+    impl Snapshot {
+        /// Counters for the tasks called `name` (or [`UNNAMED`]).
+        pub fn task(&self, name: &str) -> Option<&TaskMetrics> {
+            self.tasks.iter().find(|t| t.name == name)
+        }
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct TaskMetrics {
+        pub name: String,
+        pub spawned: u64,
+        /// Spawned and not yet dropped (finished, aborted or panicked).
+        pub alive: u64,
+        /// Ran to completion.
+        pub completed: u64,
+    }
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum ChannelKind {
+        #[default]
+        Mpsc,
+        Broadcast,
+        Watch,
+    }
+
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct ChannelMetrics {
+        pub kind: ChannelKind,
+        /// `None` for unbounded channels; 1 for watch.
+        pub capacity: Option<usize>,
+        /// Values sent and not yet received – for broadcast and watch,
+        /// by the receiver furthest behind (at most `capacity`).
+        pub depth: usize,
+        /// Successful sends so far.
+        pub sent: u64,
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct TimerMetrics {
+        pub created: u64,
+        /// Created and not yet dropped.
+        pub active: u64,
+        pub fired: u64,
+        /// How late fired timers woke up.
+        pub overshoot: Histogram,
+    }
+
+    /// Fixed‑bucket histogram; bucket `i` counts values up to
+    /// [`OVERSHOOT_BOUNDS`]`[i]`, the last one everything above.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct Histogram {
+        pub buckets: Vec<u64>,
+        pub count: u64,
+        pub sum: Duration,
+        pub max: Duration,
+    }
+
+    impl Histogram {
+        pub fn mean(&self) -> Option<Duration> {
+            let mean = self.sum.as_nanos() / u128::from(self.count.max(1));
+            (self.count > 0).then(|| Duration::from_nanos(u64::try_from(mean).unwrap_or(u64::MAX)))
+        }
+    }
+
+    /// Read every counter.
+    pub fn snapshot() -> Snapshot {
+        let tasks = lock(&TASKS)
+            .iter()
+            .map(|(name, c)| TaskMetrics {
+                name: name.clone(),
+                spawned: c.spawned.load(Relaxed),
+                alive: c.alive.load(Relaxed).max(0) as u64,
+                completed: c.completed.load(Relaxed),
+            })
+            .collect();
+
+        let mut live = lock(&CHANNELS);
+        live.retain(|w| w.strong_count() > 0);
+        let channels = live
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|c| ChannelMetrics {
+                kind: c.kind,
+                capacity: c.capacity,
+                depth: c.depth(),
+                sent: c.sent.load(Relaxed),
+            })
+            .collect();
+        drop(live);
+
+        let timers = TimerMetrics {
+            created: TIMERS_CREATED.load(Relaxed),
+            active: TIMERS_ACTIVE.load(Relaxed).max(0) as u64,
+            fired: TIMERS_FIRED.load(Relaxed),
+            overshoot: Histogram {
+                buckets: OVERSHOOT.iter().map(|b| b.load(Relaxed)).collect(),
+                count: OVERSHOOT.iter().map(|b| b.load(Relaxed)).sum(),
+                sum: Duration::from_nanos(OVERSHOOT_SUM_NS.load(Relaxed)),
+                max: Duration::from_nanos(OVERSHOOT_MAX_NS.load(Relaxed)),
+            },
+        };
+
+        Snapshot { tasks, channels, timers }
+    }
+
+    /*──────────── tasks ───────────*/
+
+    /// Counters shared by every task with the same name.
+    #[derive(Default)]
+    pub(crate) struct TaskCell {
+        spawned: AtomicU64,
+        alive: AtomicI64,
+        completed: AtomicU64,
+    }
+
+    impl TaskCell {
+        pub(super) fn completed(&self) { self.completed.fetch_add(1, Relaxed); }
+        pub(super) fn dropped(&self) { self.alive.fetch_sub(1, Relaxed); }
+    }
+
+    pub(super) fn named<T>(name: Option<&str>, spawn: impl FnOnce() -> T) -> T {
+        let prev = SPAWN_NAME.with(|n| n.replace(name.map(str::to_owned)));
+        let out = spawn();
+        SPAWN_NAME.with(|n| *n.borrow_mut() = prev);
+        out
+    }
+
+    pub(super) fn task_spawned() -> Arc<TaskCell> {
+        let name = SPAWN_NAME.with(|n| n.borrow_mut().take());
+        let name = name.unwrap_or_else(|| UNNAMED.to_owned());
+        let cell = lock(&TASKS).entry(name).or_default().clone();
+        cell.spawned.fetch_add(1, Relaxed);
+        cell.alive.fetch_add(1, Relaxed);
+        cell
+    }
+
+    /*──────────── channels ───────────*/
+
+    pub(crate) struct ChannelCell {
+        kind: ChannelKind,
+        capacity: Option<usize>,
+        depth: AtomicI64,                     /* mpsc */
+        sent: AtomicU64,
+        cursors: Mutex<Vec<Weak<AtomicU64>>>, /* broadcast / watch receivers */
+    }
+
+    impl ChannelCell {
+        pub(super) fn add(&self, n: i64) { self.depth.fetch_add(n, Relaxed); }
+        pub(super) fn sent(&self) { self.sent.fetch_add(1, Relaxed); }
+        pub(super) fn sent_count(&self) -> u64 { self.sent.load(Relaxed) }
+
+        /// A new receiver position, `at` sends in.
+        pub(super) fn cursor_at(&self, at: u64) -> Arc<AtomicU64> {
+            let pos = Arc::new(AtomicU64::new(at));
+            let mut cursors = lock(&self.cursors);
+            cursors.retain(|w| w.strong_count() > 0);
+            cursors.push(Arc::downgrade(&pos));
+            pos
+        }
+
+        fn depth(&self) -> usize {
+            if self.kind == ChannelKind::Mpsc {
+                return self.depth.load(Relaxed).max(0) as usize;
+            }
+            let sent = self.sent.load(Relaxed);
+            let behind = lock(&self.cursors)
+                .iter()
+                .filter_map(Weak::upgrade)
+                .map(|pos| sent.saturating_sub(pos.load(Relaxed)))
+                .max()
+                .unwrap_or(0);
+            usize::try_from(behind).unwrap_or(usize::MAX).min(self.capacity.unwrap_or(usize::MAX))
+        }
+    }
+
+    pub(super) fn channel_created(kind: ChannelKind, capacity: Option<usize>) -> Arc<ChannelCell> {
+        let cell = Arc::new(ChannelCell {
+            kind,
+            capacity,
+            depth: AtomicI64::new(0),
+            sent: AtomicU64::new(0),
+            cursors: Mutex::new(Vec::new()),
+        });
+        let mut live = lock(&CHANNELS);
+        live.retain(|w| w.strong_count() > 0);
+        live.push(Arc::downgrade(&cell));
+        cell
+    }
+
+    /*──────────── timers ───────────*/
+
+    pub(crate) struct TimerStat {
+        deadline: Option<Instant>,
+    }
+
+    impl TimerStat {
+        pub(super) fn start(now: Instant, d: Duration) -> Self {
+            TIMERS_CREATED.fetch_add(1, Relaxed);
+            TIMERS_ACTIVE.fetch_add(1, Relaxed);
+            Self { deadline: now.checked_add(d) }
+        }
+
+        pub(super) fn fired(&mut self, now: Instant) {
+            let Some(deadline) = self.deadline.take() else { return };
+            TIMERS_FIRED.fetch_add(1, Relaxed);
+
+            let late = now.duration_since(deadline);
+            let bucket = OVERSHOOT_BOUNDS.iter().position(|b| late <= *b).unwrap_or(OVERSHOOT_BOUNDS.len());
+            OVERSHOOT[bucket].fetch_add(1, Relaxed);
+            let ns = u64::try_from(late.as_nanos()).unwrap_or(u64::MAX);
+            OVERSHOOT_SUM_NS.fetch_add(ns, Relaxed);
+            OVERSHOOT_MAX_NS.fetch_max(ns, Relaxed);
+        }
+    }
+
+    impl Drop for TimerStat {
+        fn drop(&mut self) { TIMERS_ACTIVE.fetch_sub(1, Relaxed); }
+    }
+}
@@ -0,0 +1,117 @@
+//! Panic isolation for spawned tasks.
+//!
+//! A panicking task never takes its executor down: the panic is caught
+//! (`catch_unwind`) and its [`JoinHandle`](crate::task::JoinHandle)
+//! yields [`JoinError::Panicked`](crate::task::JoinError::Panicked).
+//! Tasks started through [`TaskBuilder`](crate::task::TaskBuilder) also
+//! hand a [`PanicReport`] to the process‑wide handler, so a panic nobody
+//! awaits still shows up with the task's name.
+//!
+//! `wasm32-unknown-unknown` builds abort on panic by default; there the
+//! report cannot run and the instance still dies.
+
+use core::{
+    any::Any,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::RwLock,
+};
+
+type Handler = Box<dyn Fn(&PanicReport<'_>) + Send + Sync>;
+
+static HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+
+/*──────────────────────── report ──────────────────────────────*/
+
+/// What the panic handler learns about a task that panicked.
+#[derive(Debug, Clone, Copy)]
+pub struct PanicReport<'a> {
+    name: Option<&'a str>,
+    message: &'a str,
+}
+
+impl PanicReport<'_> {
+    /// Name given with [`TaskBuilder::name`](crate::task::TaskBuilder::name).
+    pub fn name(&self) -> Option<&str> { self.name }
+
+    /// The panic message, or `"Box<dyn Any>"` for non‑string payloads.
+    pub fn message(&self) -> &str { self.message }
+}
+
+impl fmt::Display for PanicReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "task `{name}` panicked: {}", self.message),
+            None       => write!(f, "task panicked: {}", self.message),
+        }
+    }
+}
+
+/// Replace the panic handler (default: print the report to stderr).
+///
+/// Runs on the thread that polled the task, right after the panic.
+pub fn set_panic_handler(handler: impl Fn(&PanicReport<'_>) + Send + Sync + 'static) {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(handler));
+}
+
+fn report(name: Option<&str>, payload: &(dyn Any + Send)) {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+    let report = PanicReport { name, message };
+
+    match &*HANDLER.read().unwrap_or_else(|e| e.into_inner()) {
+        Some(handler) => handler(&report),
+        None          => std::eprintln!("{report}"),
+    }
+}
+
+/*──────────────────────── futures ─────────────────────────────*/
+
+/// Run `fut`, reporting a panic under `name` before letting it unwind on
+/// into the backend's own isolation.
+pub(crate) async fn reporting<F: Future>(name: Option<String>, fut: F) -> F::Output {
+    match catch_unwind(fut).await {
+        Ok(v)        => v,
+        Err(payload) => {
+            report(name.as_deref(), &*payload);
+            panic::resume_unwind(payload)
+        }
+    }
+}
+
+/// [`reporting`] for a blocking closure.
+pub(crate) fn reporting_blocking<T>(name: Option<String>, f: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(v)        => v,
+        Err(payload) => {
+            report(name.as_deref(), &*payload);
+            panic::resume_unwind(payload)
+        }
+    }
+}
+
+/// `fut` with every poll behind `catch_unwind`.
+pub(crate) fn catch_unwind<F: Future>(fut: F) -> CatchUnwind<F> { CatchUnwind(fut) }
+
+pub(crate) struct CatchUnwind<F>(F);
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = Result<F::Output, Box<dyn Any + Send>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: the inner future is structurally pinned and never moved.
+        let fut = unsafe { self.map_unchecked_mut(|s| &mut s.0) };
+        match panic::catch_unwind(AssertUnwindSafe(|| fut.poll(cx))) {
+            Ok(poll)     => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
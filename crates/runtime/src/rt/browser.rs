@@ -1,9 +1,10 @@
 //! wasm‑bindgen (browser / worker)
 
-use crate::api::*;
+use super::portable::{
+    self, broadcast, watch, BoundedReceiver, BoundedSender, OneshotReceiver, OneshotSender, Receiver, Sender,
+};
+use crate::{api::*, handle::JoinHandle};
 use core::time::Duration;
-use futures_channel::mpsc;
-use futures_util::future::{select, Either};
 use gloo_timers::future::TimeoutFuture;
 use wasm_bindgen_futures::spawn_local;
 
@@ -14,42 +15,69 @@ pub struct BrowserRt;
 /*───────────────── implementation ───────────────────*/
 
 impl Runtime for BrowserRt {
-    type Sender<T: 'static + MaybeSend> = mpsc::UnboundedSender<T>;
-    type Receiver<T: 'static + MaybeSend> = mpsc::UnboundedReceiver<T>;
-    type JoinHandle = ();
+    type Sender<T: 'static + MaybeSend> = Sender<T>;
+    type Receiver<T: 'static + MaybeSend> = Receiver<T>;
+    type BoundedSender<T: 'static + MaybeSend> = BoundedSender<T>;
+    type BoundedReceiver<T: 'static + MaybeSend> = BoundedReceiver<T>;
+    type OneshotSender<T: 'static + MaybeSend> = OneshotSender<T>;
+    type OneshotReceiver<T: 'static + MaybeSend> = OneshotReceiver<T>;
+    type BroadcastSender<T: 'static + MaybeSend + Clone> = broadcast::Sender<T>;
+    type BroadcastReceiver<T: 'static + MaybeSend + Clone> = broadcast::Receiver<T>;
+    type WatchSender<T: 'static + MaybeSend> = watch::Sender<T>;
+    type WatchReceiver<T: 'static + MaybeSend> = watch::Receiver<T>;
 
-    fn spawn<F>(fut: F) -> Self::JoinHandle
+    fn spawn<F>(fut: F) -> JoinHandle<F::Output>
     where
-        F: core::future::Future<Output=()> + MaybeSend + 'static,
+        F: core::future::Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
     {
-        spawn_local(fut)
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        spawn_local(fut);
+        handle
+    }
+
+    fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: core::future::Future + 'static,
+        F::Output: 'static,
+    {
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        spawn_local(fut);
+        handle
+    }
+
+    fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        /* no threads to hand it to: run inline as its own task */
+        Self::spawn_local(async move { f() })
     }
 
     fn channel<T: 'static + MaybeSend>()
         -> (Self::Sender<T>, Self::Receiver<T>)
-    { mpsc::unbounded() }
+    { portable::unbounded() }
 
-    async fn sleep(d: Duration)
-    { TimeoutFuture::new(d.as_millis() as u32).await }
+    fn channel_bounded<T: 'static + MaybeSend>(cap: usize)
+        -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>)
+    { portable::bounded(cap) }
 
-    async fn yield_now() { js_yield().await }
+    fn oneshot<T: 'static + MaybeSend>()
+        -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>)
+    { portable::oneshot() }
 
-    async fn timeout<F, T, E>(d: Duration, fut: F)
-                              -> Result<Result<T, E>, ()>
-    where
-        F: core::future::Future<Output=Result<T, E>> + MaybeSend + 'static,
-    {
-        let timer = TimeoutFuture::new(d.as_millis() as u32);
-        futures_util::pin_mut!(fut, timer);
-        Ok(match select(fut, timer).await {
-            Either::Left((out, _)) => out,
-            Either::Right((_, _)) => return Err(()),
-        })
-    }
-}
+    fn broadcast<T: 'static + MaybeSend + Clone>(cap: usize)
+        -> (Self::BroadcastSender<T>, Self::BroadcastReceiver<T>)
+    { broadcast::channel(cap) }
+
+    fn watch<T: 'static + MaybeSend>(init: T)
+        -> (Self::WatchSender<T>, Self::WatchReceiver<T>)
+    { watch::channel(init) }
 
-/*───────────────── sender helper ───────────────────*/
+    type Sleep = TimeoutFuture;
 
-impl<T: 'static + MaybeSend> SendMessage<T> for mpsc::UnboundedSender<T> {
-    fn try_send(&self, v: T) -> Result<(), ()> { self.unbounded_send(v).map_err(|_| ()) }
+    fn sleep(d: Duration) -> Self::Sleep { TimeoutFuture::new(d.as_millis() as u32) }
+
+    async fn yield_now() { js_yield().await }
 }
@@ -0,0 +1,269 @@
+//! Deterministic virtual‑time runtime for tests.
+//!
+//! * single‑threaded executor living in a thread‑local – every task,
+//!   timer and the clock belong to the thread that touches them;
+//! * time only moves through [`MockRt::advance`], or automatically in
+//!   [`MockRt::block_on`] once every task is idle;
+//! * nothing ever really sleeps, so timer tests run in microseconds.
+
+use super::portable::{
+    self, broadcast, watch, BoundedReceiver, BoundedSender, OneshotReceiver, OneshotSender, Receiver, Sender,
+};
+use crate::{api::*, clock::Instant, handle::JoinHandle};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use futures_util::future::{poll_fn, LocalBoxFuture};
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    task::Wake,
+};
+
+/// Virtual‑time backend; see the [module docs](self).
+pub struct MockRt;
+
+/*───────────────── executor state ───────────────────*/
+
+struct State {
+    origin: Instant,
+    elapsed: Cell<Duration>,
+    tasks: RefCell<HashMap<u64, LocalBoxFuture<'static, ()>>>,
+    next_id: Cell<u64>,
+    ready: Arc<Mutex<VecDeque<u64>>>,   /* shared with (Send) wakers */
+    timers: RefCell<BTreeMap<(Instant, u64), Waker>>,
+}
+
+thread_local! {
+    static STATE: State = State {
+        origin: Instant::now(),
+        elapsed: Cell::new(Duration::ZERO),
+        tasks: RefCell::default(),
+        next_id: Cell::new(0),
+        ready: Arc::default(),
+        timers: RefCell::default(),
+    };
+}
+
+fn with<R>(f: impl FnOnce(&State) -> R) -> R { STATE.with(f) }
+
+impl State {
+    fn id(&self) -> u64 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        id
+    }
+
+    fn now(&self) -> Instant { self.origin + self.elapsed.get() }
+
+    fn pop_ready(&self) -> Option<u64> { portable::lock(&self.ready).pop_front() }
+
+    /// Move the clock to `t` (never backwards) and wake every timer due.
+    fn set_time(&self, t: Instant) {
+        if t > self.now() { self.elapsed.set(t - self.origin); }
+        let now = self.now();
+        let mut timers = self.timers.borrow_mut();
+        while let Some(entry) = timers.first_entry() {
+            if entry.key().0 > now { break; }
+            entry.remove().wake();
+        }
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.timers.borrow().first_key_value().map(|(&(t, _), _)| t)
+    }
+}
+
+struct TaskWaker {
+    id: u64,
+    ready: Arc<Mutex<VecDeque<u64>>>,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) { self.wake_by_ref() }
+    fn wake_by_ref(self: &Arc<Self>) { portable::lock(&self.ready).push_back(self.id); }
+}
+
+/*───────────────── driving ──────────────────────────*/
+
+impl MockRt {
+    /// Poll every runnable task until none is left; the clock stays put.
+    pub fn run_until_stalled() {
+        while let Some(id) = with(State::pop_ready) {
+            /* take the task out so it may spawn (or drive) while polled */
+            let Some(mut fut) = with(|s| s.tasks.borrow_mut().remove(&id)) else { continue };
+            let ready = with(|s| s.ready.clone());
+            let waker = Waker::from(Arc::new(TaskWaker { id, ready }));
+            if fut.as_mut().poll(&mut Context::from_waker(&waker)).is_pending() {
+                with(|s| s.tasks.borrow_mut().insert(id, fut));
+            }
+        }
+    }
+
+    /// Move virtual time forward by `d`, firing timers in deadline order
+    /// and letting tasks react after each one.
+    pub fn advance(d: Duration) {
+        let target = Self::now() + d;
+        Self::run_until_stalled();
+        while let Some(t) = with(State::next_deadline).filter(|&t| t <= target) {
+            with(|s| s.set_time(t));
+            Self::run_until_stalled();
+        }
+        with(|s| s.set_time(target));
+        Self::run_until_stalled();
+    }
+
+    /// Virtual time passed since this thread first used the mock.
+    pub fn elapsed() -> Duration { with(|s| s.elapsed.get()) }
+
+    /// Drive `fut` and every spawned task to completion of `fut`.
+    ///
+    /// Whenever nothing is runnable the clock jumps straight to the next
+    /// timer (auto‑advance).
+    ///
+    /// # Panics
+    /// If `fut` is pending with no runnable task and no timer left.
+    pub fn block_on<F: Future>(fut: F) -> F::Output {
+        struct Flag(AtomicBool);
+        impl Wake for Flag {
+            fn wake(self: Arc<Self>) { self.0.store(true, Ordering::Release); }
+        }
+
+        let flag = Arc::new(Flag(AtomicBool::new(true)));
+        let waker = Waker::from(flag.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = core::pin::pin!(fut);
+        loop {
+            if flag.0.swap(false, Ordering::AcqRel) {
+                if let Poll::Ready(v) = fut.as_mut().poll(&mut cx) { return v; }
+            }
+            Self::run_until_stalled();
+            if flag.0.load(Ordering::Acquire) { continue; }
+            match with(State::next_deadline) {
+                Some(t) => with(|s| s.set_time(t)),
+                None    => panic!("MockRt::block_on: future is pending but nothing can wake it"),
+            }
+        }
+    }
+}
+
+/*───────────────── sleep ────────────────────────────*/
+
+/// Virtual‑clock sleep returned by [`MockRt::sleep`](Runtime::sleep).
+pub struct Sleep {
+    deadline: Option<Instant>,      /* `None` → beyond the clock's range */
+    key: Option<u64>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let Some(deadline) = self.deadline else { return Poll::Pending };
+        with(|s| {
+            if s.now() >= deadline { return Poll::Ready(()); }
+            let key = *self.key.get_or_insert_with(|| s.id());
+            s.timers.borrow_mut().insert((deadline, key), cx.waker().clone());
+            Poll::Pending
+        })
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        if let (Some(deadline), Some(key)) = (self.deadline, self.key) {
+            /* `try_with`: the thread‑local may already be gone at exit */
+            let _ = STATE.try_with(|s| s.timers.borrow_mut().remove(&(deadline, key)));
+        }
+    }
+}
+
+/*───────────────── implementation ───────────────────*/
+
+impl Runtime for MockRt {
+    type Sender<T: 'static + MaybeSend> = Sender<T>;
+    type Receiver<T: 'static + MaybeSend> = Receiver<T>;
+    type BoundedSender<T: 'static + MaybeSend> = BoundedSender<T>;
+    type BoundedReceiver<T: 'static + MaybeSend> = BoundedReceiver<T>;
+    type OneshotSender<T: 'static + MaybeSend> = OneshotSender<T>;
+    type OneshotReceiver<T: 'static + MaybeSend> = OneshotReceiver<T>;
+    type BroadcastSender<T: 'static + MaybeSend + Clone> = broadcast::Sender<T>;
+    type BroadcastReceiver<T: 'static + MaybeSend + Clone> = broadcast::Receiver<T>;
+    type WatchSender<T: 'static + MaybeSend> = watch::Sender<T>;
+    type WatchReceiver<T: 'static + MaybeSend> = watch::Receiver<T>;
+
+    fn spawn<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: core::future::Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
+    {
+        Self::spawn_local(fut)
+    }
+
+    fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: core::future::Future + 'static,
+        F::Output: 'static,
+    {
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        with(|s| {
+            let id = s.id();
+            s.tasks.borrow_mut().insert(id, Box::pin(fut));
+            portable::lock(&s.ready).push_back(id);
+        });
+        handle
+    }
+
+    fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        /* inline keeps virtual time deterministic */
+        Self::spawn_local(async move { f() })
+    }
+
+    fn channel<T: 'static + MaybeSend>()
+        -> (Self::Sender<T>, Self::Receiver<T>)
+    { portable::unbounded() }
+
+    fn channel_bounded<T: 'static + MaybeSend>(cap: usize)
+        -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>)
+    { portable::bounded(cap) }
+
+    fn oneshot<T: 'static + MaybeSend>()
+        -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>)
+    { portable::oneshot() }
+
+    fn broadcast<T: 'static + MaybeSend + Clone>(cap: usize)
+        -> (Self::BroadcastSender<T>, Self::BroadcastReceiver<T>)
+    { broadcast::channel(cap) }
+
+    fn watch<T: 'static + MaybeSend>(init: T)
+        -> (Self::WatchSender<T>, Self::WatchReceiver<T>)
+    { watch::channel(init) }
+
+    type Sleep = Sleep;
+
+    fn sleep(d: Duration) -> Self::Sleep {
+        Sleep { deadline: Self::now().checked_add(d), key: None }
+    }
+
+    fn now() -> Instant { with(State::now) }
+
+    async fn yield_now() {
+        let mut yielded = false;
+        poll_fn(|cx| {
+            if yielded { return Poll::Ready(()); }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+            .await
+    }
+}
@@ -1,16 +1,29 @@
 //! Select exactly **one** concrete backend; re‑export it as `Rt`.
 
 cfg_if::cfg_if! {
-    if #[cfg(all(feature = "native", not(target_arch = "wasm32")))] {
+    if #[cfg(all(feature = "smol", not(target_arch = "wasm32")))] {
+        mod smol;
+        pub use smol::{block_on, enter, SmolRt as Rt};
+    } else if #[cfg(all(feature = "native", not(target_arch = "wasm32")))] {
         mod native;
-        pub use native::TokioRt as Rt;
+        pub use native::{block_on, enter, TokioRt as Rt};
     } else if #[cfg(all(feature = "browser", target_arch = "wasm32"))] {
         mod browser;
         pub use browser::BrowserRt as Rt;
     } else if #[cfg(all(feature = "wasi", target_arch = "wasm32"))] {
         mod wasi;
-        pub use wasi::WasiRt as Rt;
+        pub use wasi::{block_on, WasiRt as Rt};
     } else {
-        compile_error!("Enable **exactly one** of: native | browser | wasi");
+        compile_error!("Enable **exactly one** of: native | smol | browser | wasi");
     }
 }
+
+/* futures‑channel glue shared by smol, browser, WASI and the mock */
+#[cfg(any(feature = "smol", feature = "browser", feature = "wasi", feature = "mock"))]
+mod portable;
+
+/* virtual‑time test backend, usable next to any of the above */
+#[cfg(feature = "mock")]
+mod mock;
+#[cfg(feature = "mock")]
+pub use mock::MockRt;
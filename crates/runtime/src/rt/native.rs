@@ -1,47 +1,319 @@
 //! Tokio (native host OS)
 
-use crate::api::*;
+use crate::{api::*, handle::JoinHandle, metrics::{Cursor, Depth, Fanout}};
 use std::time::Duration;
+use core::{future::Future, ops::Deref, pin::Pin, task::{Context, Poll}};
+use futures_util::{FutureExt, Sink, Stream};
 use tokio::{
-    sync::mpsc,
-    task::{self, JoinHandle},
+    sync::{broadcast, mpsc, oneshot, watch},
+    task,
 };
 
 pub struct TokioRt;
 
+/// Fresh current‑thread runtime (with a `LocalSet`) just for `fut`.
+///
+/// # Panics
+/// When called from inside another Tokio runtime.
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build the Tokio runtime")
+        .block_on(task::LocalSet::new().run_until(fut))
+}
+
+/// Entry point behind `#[main]`: a runtime of the requested flavour.
+pub fn enter<F: Future>(multi_thread: bool, workers: Option<usize>, fut: F) -> F::Output {
+    let mut builder = match multi_thread {
+        true  => tokio::runtime::Builder::new_multi_thread(),
+        false => tokio::runtime::Builder::new_current_thread(),
+    };
+    if let Some(n) = workers { builder.worker_threads(n); }
+    builder
+        .enable_all()
+        .build()
+        .expect("failed to build the Tokio runtime")
+        .block_on(task::LocalSet::new().run_until(fut))
+}
+
 /*───────────────── implementation ───────────────────*/
 
 impl Runtime for TokioRt {
-    type Sender<T: 'static + MaybeSend> = mpsc::UnboundedSender<T>;
-    type Receiver<T: 'static + MaybeSend> = mpsc::UnboundedReceiver<T>;
-    type JoinHandle = JoinHandle<()>;
+    type Sender<T: 'static + MaybeSend> = Sender<T>;
+    type Receiver<T: 'static + MaybeSend> = Receiver<T>;
+    type BoundedSender<T: 'static + MaybeSend> = BoundedSender<T>;
+    type BoundedReceiver<T: 'static + MaybeSend> = BoundedReceiver<T>;
+    type OneshotSender<T: 'static + MaybeSend> = OneshotSender<T>;
+    type OneshotReceiver<T: 'static + MaybeSend> = OneshotReceiver<T>;
+    type BroadcastSender<T: 'static + MaybeSend + Clone> = BroadcastSender<T>;
+    type BroadcastReceiver<T: 'static + MaybeSend + Clone> = BroadcastReceiver<T>;
+    type WatchSender<T: 'static + MaybeSend> = WatchSender<T>;
+    type WatchReceiver<T: 'static + MaybeSend> = WatchReceiver<T>;
 
-    fn spawn<F>(fut: F) -> Self::JoinHandle
+    fn spawn<F>(fut: F) -> JoinHandle<F::Output>
     where
-        F: core::future::Future<Output=()> + MaybeSend + 'static,
+        F: Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
     {
-        task::spawn(fut)
+        JoinHandle::tokio(task::spawn(crate::hook::wrap(fut)))
+    }
+
+    fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        JoinHandle::tokio(task::spawn_local(crate::hook::wrap(fut)))
+    }
+
+    fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        /* hooks, metrics and task‑locals apply as for any spawn: the body
+         * runs inside the one poll of a hooked future */
+        let hooked = crate::hook::wrap(async move { f() });
+        JoinHandle::tokio(task::spawn_blocking(move || {
+            hooked.now_or_never().expect("a blocking body finishes in one poll")
+        }))
+    }
+
+    async fn run_local<F: Future>(fut: F) -> F::Output {
+        task::LocalSet::new().run_until(fut).await
     }
 
     fn channel<T: 'static + MaybeSend>()
         -> (Self::Sender<T>, Self::Receiver<T>)
-    { mpsc::unbounded_channel() }
+    {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let depth = Depth::new(None);
+        (Sender(tx, depth.clone()), Receiver(rx, depth))
+    }
 
-    async fn sleep(d: Duration) { tokio::time::sleep(d).await }
+    fn channel_bounded<T: 'static + MaybeSend>(cap: usize)
+        -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>)
+    {
+        let (tx, rx) = mpsc::channel(cap);
+        let depth = Depth::new(Some(cap));
+        (BoundedSender(tx, depth.clone()), BoundedReceiver(rx, depth))
+    }
 
-    async fn timeout<F, T, E>(d: Duration, fut: F)
-                              -> Result<Result<T, E>, ()>
-    where
-        F: core::future::Future<Output=Result<T, E>> + MaybeSend + 'static,
+    fn oneshot<T: 'static + MaybeSend>()
+        -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>)
+    {
+        let (tx, rx) = oneshot::channel();
+        (OneshotSender(tx), OneshotReceiver(rx))
+    }
+
+    fn broadcast<T: 'static + MaybeSend + Clone>(cap: usize)
+        -> (Self::BroadcastSender<T>, Self::BroadcastReceiver<T>)
+    {
+        let (tx, rx) = broadcast::channel(cap);
+        let fanout = Fanout::broadcast(cap.next_power_of_two());
+        let cursor = fanout.cursor();
+        (BroadcastSender(tx, fanout), BroadcastReceiver(rx, cursor))
+    }
+
+    fn watch<T: 'static + MaybeSend>(init: T)
+        -> (Self::WatchSender<T>, Self::WatchReceiver<T>)
     {
-        tokio::time::timeout(d, fut).await.map_err(|_| ())
+        let (tx, rx) = watch::channel(init);
+        let fanout = Fanout::watch();
+        let cursor = fanout.cursor();
+        (WatchSender(tx, fanout), WatchReceiver(rx, cursor))
     }
 
+    type Sleep = tokio::time::Sleep;
+
+    fn sleep(d: Duration) -> Self::Sleep { tokio::time::sleep(d) }
+
     async fn yield_now() { task::yield_now().await }
 }
 
-/*───────────────── sender helper ───────────────────*/
+/*───────────────── unbounded channel ────────────────*/
+
+/// Tokio `mpsc::UnboundedSender`, also usable as a `Sink`.
+pub struct Sender<T>(mpsc::UnboundedSender<T>, Depth);
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+impl<T: 'static + MaybeSend> SendMessage<T> for Sender<T> {
+    fn try_send(&self, v: T) -> Result<(), SendError<T>> {
+        self.1.sent(self.0.send(v).map_err(|e| SendError(e.0)))
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = Closed;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(if self.0.is_closed() { Err(Closed) } else { Ok(()) })
+    }
+    fn start_send(self: Pin<&mut Self>, v: T) -> Result<(), Closed> {
+        self.1.sent(self.0.send(v).map_err(|_| Closed))
+    }
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Tokio `mpsc::UnboundedReceiver` behind [`ReceiverExt`] and `Stream`.
+pub struct Receiver<T>(mpsc::UnboundedReceiver<T>, Depth);
+
+impl<T: 'static + MaybeSend> ReceiverExt<T> for Receiver<T> {
+    async fn recv(&mut self) -> Option<T> { self.1.recv(self.0.recv().await) }
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.1.try_recv(self.0.try_recv().map_err(try_recv_error))
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        this.1.poll(this.0.poll_recv(cx))
+    }
+}
 
-impl<T: 'static + MaybeSend> SendMessage<T> for mpsc::UnboundedSender<T> {
-    fn try_send(&self, v: T) -> Result<(), ()> { self.send(v).map_err(|_| ()) }
+fn try_recv_error(e: mpsc::error::TryRecvError) -> TryRecvError {
+    match e {
+        mpsc::error::TryRecvError::Empty        => TryRecvError::Empty,
+        mpsc::error::TryRecvError::Disconnected => TryRecvError::Closed,
+    }
+}
+
+/*───────────────── bounded sender ───────────────────*/
+
+/// Tokio `mpsc::Sender` behind the crate‑wide [`BoundedSendMessage`] API.
+pub struct BoundedSender<T>(mpsc::Sender<T>, Depth);
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+impl<T: 'static + MaybeSend> BoundedSendMessage<T> for BoundedSender<T> {
+    async fn send(&mut self, v: T) -> Result<(), SendError<T>> {
+        self.1.sent(self.0.send(v).await.map_err(|e| SendError(e.0)))
+    }
+
+    fn try_send(&mut self, v: T) -> Result<(), TrySendError<T>> {
+        use mpsc::error::TrySendError as Tokio;
+        self.1.sent(self.0.try_send(v).map_err(|e| match e {
+            Tokio::Full(v)   => TrySendError::Full(v),
+            Tokio::Closed(v) => TrySendError::Closed(v),
+        }))
+    }
+}
+
+/// Tokio `mpsc::Receiver` behind [`ReceiverExt`] and `Stream`.
+pub struct BoundedReceiver<T>(mpsc::Receiver<T>, Depth);
+
+impl<T: 'static + MaybeSend> ReceiverExt<T> for BoundedReceiver<T> {
+    async fn recv(&mut self) -> Option<T> { self.1.recv(self.0.recv().await) }
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.1.try_recv(self.0.try_recv().map_err(try_recv_error))
+    }
+}
+
+impl<T> Stream for BoundedReceiver<T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        this.1.poll(this.0.poll_recv(cx))
+    }
+}
+
+/*───────────────── one‑shot ─────────────────────────*/
+
+/// Tokio one‑shot sender behind [`OneshotSend`].
+pub struct OneshotSender<T>(oneshot::Sender<T>);
+
+impl<T> OneshotSend<T> for OneshotSender<T> {
+    fn send(self, v: T) -> Result<(), SendError<T>> { self.0.send(v).map_err(SendError) }
+}
+
+/// Tokio one‑shot receiver resolving to the crate's [`RecvError`].
+pub struct OneshotReceiver<T>(oneshot::Receiver<T>);
+
+impl<T> Future for OneshotReceiver<T> {
+    type Output = Result<T, RecvError>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map_err(|_| RecvError)
+    }
+}
+
+/*───────────────── broadcast ────────────────────────*/
+
+pub struct BroadcastSender<T>(broadcast::Sender<T>, Fanout);
+pub struct BroadcastReceiver<T>(broadcast::Receiver<T>, Cursor);
+
+impl<T> Clone for BroadcastSender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+impl<T: 'static + MaybeSend + Clone> BroadcastSend<T> for BroadcastSender<T> {
+    type Receiver = BroadcastReceiver<T>;
+
+    fn send(&self, v: T) -> Result<usize, SendError<T>> {
+        self.1.sent(self.0.send(v).map_err(|e| SendError(e.0)))
+    }
+    fn subscribe(&self) -> Self::Receiver { BroadcastReceiver(self.0.subscribe(), self.1.cursor()) }
+    fn receiver_count(&self) -> usize { self.0.receiver_count() }
+}
+
+impl<T: 'static + MaybeSend + Clone> BroadcastRecv<T> for BroadcastReceiver<T> {
+    async fn recv(&mut self) -> Result<T, BroadcastRecvError> {
+        use broadcast::error::RecvError as Tokio;
+        self.1.recv(self.0.recv().await.map_err(|e| match e {
+            Tokio::Closed    => BroadcastRecvError::Closed,
+            Tokio::Lagged(n) => BroadcastRecvError::Lagged(n),
+        }))
+    }
+}
+
+/*───────────────── watch ────────────────────────────*/
+
+pub struct WatchSender<T>(watch::Sender<T>, Fanout);
+pub struct WatchReceiver<T>(watch::Receiver<T>, Cursor);
+
+impl<T> Clone for WatchReceiver<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.fork()) }
+}
+
+impl<T: 'static + MaybeSend> WatchSend<T> for WatchSender<T> {
+    type Receiver = WatchReceiver<T>;
+
+    fn send(&self, v: T) -> Result<(), SendError<T>> {
+        self.1.sent(self.0.send(v).map_err(|e| SendError(e.0)))
+    }
+    fn send_replace(&self, v: T) -> T {
+        self.1.stored();
+        self.0.send_replace(v)
+    }
+    fn borrow(&self) -> impl Deref<Target = T> + '_ { self.0.borrow() }
+    fn subscribe(&self) -> Self::Receiver { WatchReceiver(self.0.subscribe(), self.1.cursor()) }
+    fn receiver_count(&self) -> usize { self.0.receiver_count() }
+}
+
+impl<T: 'static + MaybeSend> WatchRecv<T> for WatchReceiver<T> {
+    async fn changed(&mut self) -> Result<(), RecvError> {
+        self.0.changed().await.map_err(|_| RecvError)?;
+        self.1.caught_up();
+        Ok(())
+    }
+    fn has_changed(&self) -> Result<bool, RecvError> {
+        self.0.has_changed().map_err(|_| RecvError)
+    }
+    fn borrow(&self) -> impl Deref<Target = T> + '_ { self.0.borrow() }
+    fn borrow_and_update(&mut self) -> impl Deref<Target = T> + '_ {
+        self.1.caught_up();
+        self.0.borrow_and_update()
+    }
 }
@@ -0,0 +1,132 @@
+//! Ring‑buffer broadcast with Tokio's lag semantics.
+//!
+//! * capacity is rounded up to a power of two;
+//! * once full, the oldest value is overwritten and receivers still
+//!   pointing at it get `Lagged(n)` before resuming at the oldest value;
+//! * a send with no live receivers fails and is not retained.
+
+use super::lock;
+use crate::{api::*, metrics::{Cursor, Fanout}};
+use futures_util::future::poll_fn;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+struct State<T> {
+    buf: VecDeque<T>,
+    cap: usize,
+    head: u64,          /* sequence number of `buf[0]` */
+    senders: usize,
+    receivers: usize,
+    wakers: Vec<Waker>,
+    fanout: Fanout,
+}
+
+impl<T> State<T> {
+    fn tail(&self) -> u64 { self.head + self.buf.len() as u64 }
+
+    fn wake_all(&mut self) { self.wakers.drain(..).for_each(Waker::wake); }
+}
+
+type Shared<T> = Arc<Mutex<State<T>>>;
+
+pub struct Sender<T>(Shared<T>);
+
+pub struct Receiver<T> {
+    shared: Shared<T>,
+    next: u64,
+    cursor: Cursor,
+}
+
+pub fn channel<T: Clone>(cap: usize) -> (Sender<T>, Receiver<T>) {
+    assert!(cap > 0, "broadcast channel requires cap > 0");
+    let cap = cap.next_power_of_two();
+    let shared = Arc::new(Mutex::new(State {
+        buf: VecDeque::with_capacity(cap),
+        cap,
+        head: 0,
+        senders: 1,
+        receivers: 1,
+        wakers: Vec::new(),
+        fanout: Fanout::broadcast(cap),
+    }));
+    let cursor = lock(&shared).fanout.cursor();
+    (Sender(shared.clone()), Receiver { shared, next: 0, cursor })
+}
+
+/*───────────────── sender ───────────────────────────*/
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        lock(&self.0).senders += 1;
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut st = lock(&self.0);
+        st.senders -= 1;
+        if st.senders == 0 { st.wake_all(); }
+    }
+}
+
+impl<T: Clone> BroadcastSend<T> for Sender<T> {
+    type Receiver = Receiver<T>;
+
+    fn send(&self, v: T) -> Result<usize, SendError<T>> {
+        let mut st = lock(&self.0);
+        if st.receivers == 0 { return Err(SendError(v)); }
+        if st.buf.len() == st.cap {
+            st.buf.pop_front();
+            st.head += 1;
+        }
+        st.buf.push_back(v);
+        st.wake_all();
+        st.fanout.sent(Ok(st.receivers))
+    }
+
+    fn subscribe(&self) -> Receiver<T> {
+        let mut st = lock(&self.0);
+        st.receivers += 1;
+        Receiver { shared: self.0.clone(), next: st.tail(), cursor: st.fanout.cursor() }
+    }
+
+    fn receiver_count(&self) -> usize { lock(&self.0).receivers }
+}
+
+/*───────────────── receiver ─────────────────────────*/
+
+impl<T: Clone> Receiver<T> {
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, BroadcastRecvError>> {
+        let mut st = lock(&self.shared);
+        if self.next < st.head {
+            let missed = st.head - self.next;
+            self.next = st.head;
+            return Poll::Ready(Err(BroadcastRecvError::Lagged(missed)));
+        }
+        if self.next < st.tail() {
+            let v = st.buf[(self.next - st.head) as usize].clone();
+            self.next += 1;
+            return Poll::Ready(Ok(v));
+        }
+        if st.senders == 0 { return Poll::Ready(Err(BroadcastRecvError::Closed)); }
+        if !st.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            st.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) { lock(&self.shared).receivers -= 1; }
+}
+
+impl<T: Clone> BroadcastRecv<T> for Receiver<T> {
+    async fn recv(&mut self) -> Result<T, BroadcastRecvError> {
+        let r = poll_fn(|cx| self.poll_recv(cx)).await;
+        self.cursor.recv(r)
+    }
+}
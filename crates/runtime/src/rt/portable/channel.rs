@@ -0,0 +1,119 @@
+//! `futures_channel::mpsc` wrapped in the crate's channel API.
+
+use crate::{api::*, metrics::Depth};
+use core::{pin::Pin, task::{Context, Poll}};
+use futures_channel::mpsc;
+use futures_util::{Sink, Stream, StreamExt};
+
+/*───────────────── unbounded channel ────────────────*/
+
+/// `futures_channel::mpsc::UnboundedSender` with the crate's `Sink` error.
+pub struct Sender<T>(mpsc::UnboundedSender<T>, Depth);
+
+pub fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    let (tx, rx) = mpsc::unbounded();
+    let depth = Depth::new(None);
+    (Sender(tx, depth.clone()), Receiver(rx, depth))
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+impl<T: 'static + MaybeSend> SendMessage<T> for Sender<T> {
+    fn try_send(&self, v: T) -> Result<(), SendError<T>> {
+        self.1.sent(self.0.unbounded_send(v).map_err(|e| SendError(e.into_inner())))
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = Closed;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Pin::new(&mut self.0).poll_ready(cx).map_err(|_| Closed)
+    }
+    fn start_send(mut self: Pin<&mut Self>, v: T) -> Result<(), Closed> {
+        let this = &mut *self;
+        this.1.sent(Pin::new(&mut this.0).start_send(v).map_err(|_| Closed))
+    }
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// `futures_channel::mpsc::UnboundedReceiver` behind [`ReceiverExt`] and `Stream`.
+pub struct Receiver<T>(mpsc::UnboundedReceiver<T>, Depth);
+
+impl<T: 'static + MaybeSend> ReceiverExt<T> for Receiver<T> {
+    async fn recv(&mut self) -> Option<T> { self.1.recv(self.0.next().await) }
+    fn try_recv(&mut self) -> Result<T, TryRecvError> { self.1.try_recv(try_recv(self.0.try_next())) }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        this.1.poll(Pin::new(&mut this.0).poll_next(cx))
+    }
+}
+
+fn try_recv<T>(r: Result<Option<T>, mpsc::TryRecvError>) -> Result<T, TryRecvError> {
+    match r {
+        Ok(Some(v)) => Ok(v),
+        Ok(None)    => Err(TryRecvError::Closed),
+        Err(_)      => Err(TryRecvError::Empty),
+    }
+}
+
+/*───────────────── bounded channel ──────────────────*/
+
+/// `futures_channel::mpsc::Sender` behind [`BoundedSendMessage`].
+pub struct BoundedSender<T>(mpsc::Sender<T>, Depth);
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+/// `futures` grants every sender one slot on top of the buffer, so the
+/// buffer is `cap - 1` to give a single producer exactly `cap` slots.
+pub fn bounded<T>(cap: usize) -> (BoundedSender<T>, BoundedReceiver<T>) {
+    assert!(cap > 0, "bounded channel requires cap > 0");
+    let (tx, rx) = mpsc::channel(cap - 1);
+    let depth = Depth::new(Some(cap));
+    (BoundedSender(tx, depth.clone()), BoundedReceiver(rx, depth))
+}
+
+impl<T: 'static + MaybeSend> BoundedSendMessage<T> for BoundedSender<T> {
+    async fn send(&mut self, v: T) -> Result<(), SendError<T>> {
+        /* wait for a slot first so `v` is never moved into a lost send */
+        let ready = futures_util::future::poll_fn(|cx| self.0.poll_ready(cx)).await;
+        if ready.is_err() { return Err(SendError(v)); }
+        self.1.sent(self.0.try_send(v).map_err(|e| SendError(e.into_inner())))
+    }
+
+    fn try_send(&mut self, v: T) -> Result<(), TrySendError<T>> {
+        self.1.sent(self.0.try_send(v).map_err(|e| {
+            if e.is_full() { TrySendError::Full(e.into_inner()) }
+            else           { TrySendError::Closed(e.into_inner()) }
+        }))
+    }
+}
+
+/// `futures_channel::mpsc::Receiver` behind [`ReceiverExt`] and `Stream`.
+pub struct BoundedReceiver<T>(mpsc::Receiver<T>, Depth);
+
+impl<T: 'static + MaybeSend> ReceiverExt<T> for BoundedReceiver<T> {
+    async fn recv(&mut self) -> Option<T> { self.1.recv(self.0.next().await) }
+    fn try_recv(&mut self) -> Result<T, TryRecvError> { self.1.try_recv(try_recv(self.0.try_next())) }
+}
+
+impl<T> Stream for BoundedReceiver<T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        this.1.poll(Pin::new(&mut this.0).poll_next(cx))
+    }
+}
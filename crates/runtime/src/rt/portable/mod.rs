@@ -0,0 +1,48 @@
+//! Executor‑agnostic plumbing shared by the `futures`‑based back‑ends
+//! (smol, browser, WASI, mock).
+
+use crate::api::*;
+use core::{future::Future, pin::Pin, task::{Context, Poll}};
+use futures_channel::oneshot;
+use std::sync::{Mutex, MutexGuard};
+
+pub mod broadcast;
+pub mod watch;
+
+/* smol brings its own mpsc (async-channel) */
+#[cfg(any(feature = "browser", feature = "wasi", feature = "mock"))]
+mod channel;
+#[cfg(any(feature = "browser", feature = "wasi", feature = "mock"))]
+pub use channel::{bounded, unbounded, BoundedReceiver, BoundedSender, Receiver, Sender};
+
+/*───────────────── one‑shot ─────────────────────────*/
+
+/// `futures` one‑shot sender behind [`OneshotSend`].
+pub struct OneshotSender<T>(oneshot::Sender<T>);
+
+impl<T> OneshotSend<T> for OneshotSender<T> {
+    fn send(self, v: T) -> Result<(), SendError<T>> { self.0.send(v).map_err(SendError) }
+}
+
+/// `futures` one‑shot receiver resolving to the crate's [`RecvError`].
+pub struct OneshotReceiver<T>(oneshot::Receiver<T>);
+
+pub fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let (tx, rx) = oneshot::channel();
+    (OneshotSender(tx), OneshotReceiver(rx))
+}
+
+impl<T> Future for OneshotReceiver<T> {
+    type Output = Result<T, RecvError>;
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map_err(|_| RecvError)
+    }
+}
+
+/*───────────────── helpers ──────────────────────────*/
+
+/// A panic while holding one of our locks leaves no torn state behind,
+/// so poisoning is ignored.
+pub(crate) fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
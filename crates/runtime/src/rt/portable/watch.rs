@@ -0,0 +1,144 @@
+//! “Latest value” cell with Tokio's `watch` semantics.
+//!
+//! Every store bumps a version; a receiver remembers the last version it
+//! marked seen, so `changed()` fires at most once per unseen value.
+
+use super::lock;
+use crate::{api::*, metrics::{Cursor, Fanout}};
+use core::ops::Deref;
+use futures_util::future::poll_fn;
+use std::{
+    sync::{Arc, Mutex, RwLock, RwLockReadGuard},
+    task::{Context, Poll, Waker},
+};
+
+struct Meta {
+    version: u64,
+    closed: bool,
+    receivers: usize,
+    wakers: Vec<Waker>,
+}
+
+struct Shared<T> {
+    value: RwLock<T>,
+    meta: Mutex<Meta>,
+    fanout: Fanout,
+}
+
+impl<T> Shared<T> {
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.value.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn store(&self, v: T) -> T {
+        let old = {
+            let mut slot = self.value.write().unwrap_or_else(|e| e.into_inner());
+            core::mem::replace(&mut *slot, v)
+        };
+        let mut meta = lock(&self.meta);
+        meta.version += 1;
+        meta.wakers.drain(..).for_each(Waker::wake);
+        self.fanout.stored();
+        old
+    }
+}
+
+pub struct Sender<T>(Arc<Shared<T>>);
+
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    seen: u64,
+    cursor: Cursor,
+}
+
+pub fn channel<T>(init: T) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        value: RwLock::new(init),
+        meta: Mutex::new(Meta { version: 0, closed: false, receivers: 1, wakers: Vec::new() }),
+        fanout: Fanout::watch(),
+    });
+    let cursor = shared.fanout.cursor();
+    (Sender(shared.clone()), Receiver { shared, seen: 0, cursor })
+}
+
+/*───────────────── sender ───────────────────────────*/
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut meta = lock(&self.0.meta);
+        meta.closed = true;
+        meta.wakers.drain(..).for_each(Waker::wake);
+    }
+}
+
+impl<T> WatchSend<T> for Sender<T> {
+    type Receiver = Receiver<T>;
+
+    fn send(&self, v: T) -> Result<(), SendError<T>> {
+        if self.receiver_count() == 0 { return Err(SendError(v)); }
+        self.0.store(v);
+        Ok(())
+    }
+
+    fn send_replace(&self, v: T) -> T { self.0.store(v) }
+
+    fn borrow(&self) -> impl Deref<Target = T> + '_ { self.0.read() }
+
+    fn subscribe(&self) -> Receiver<T> {
+        let mut meta = lock(&self.0.meta);
+        meta.receivers += 1;
+        Receiver { shared: self.0.clone(), seen: meta.version, cursor: self.0.fanout.cursor() }
+    }
+
+    fn receiver_count(&self) -> usize { lock(&self.0.meta).receivers }
+}
+
+/*───────────────── receiver ─────────────────────────*/
+
+impl<T> Receiver<T> {
+    fn poll_changed(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), RecvError>> {
+        let mut meta = lock(&self.shared.meta);
+        if meta.version != self.seen {
+            self.seen = meta.version;
+            self.cursor.caught_up();
+            return Poll::Ready(Ok(()));
+        }
+        if meta.closed { return Poll::Ready(Err(RecvError)); }
+        if !meta.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            meta.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        lock(&self.shared.meta).receivers += 1;
+        Self { shared: self.shared.clone(), seen: self.seen, cursor: self.cursor.fork() }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) { lock(&self.shared.meta).receivers -= 1; }
+}
+
+impl<T> WatchRecv<T> for Receiver<T> {
+    async fn changed(&mut self) -> Result<(), RecvError> {
+        poll_fn(|cx| self.poll_changed(cx)).await
+    }
+
+    fn has_changed(&self) -> Result<bool, RecvError> {
+        let meta = lock(&self.shared.meta);
+        if meta.closed { return Err(RecvError); }
+        Ok(meta.version != self.seen)
+    }
+
+    fn borrow(&self) -> impl Deref<Target = T> + '_ { self.shared.read() }
+
+    fn borrow_and_update(&mut self) -> impl Deref<Target = T> + '_ {
+        /* mark seen *before* reading so a racing store re‑triggers `changed` */
+        self.seen = lock(&self.shared.meta).version;
+        self.cursor.caught_up();
+        self.shared.read()
+    }
+}
@@ -0,0 +1,255 @@
+//! smol stack (native host OS, no Tokio)
+//!
+//! * `spawn`          → one process‑wide `async_executor::Executor`,
+//!   driven by a worker pool and by every [`block_on`] caller;
+//! * `spawn_local`    → a per‑thread `LocalExecutor`, driven inside
+//!   [`Runtime::run_local`] / [`block_on`];
+//! * `sleep`          → `async_io::Timer`;
+//! * `spawn_blocking` → the `blocking` thread pool.
+
+use super::portable::{self, broadcast, watch, OneshotReceiver, OneshotSender};
+use crate::{api::*, handle::JoinHandle, metrics::Depth};
+use async_executor::{Executor, LocalExecutor};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use futures_util::{future::{pending, poll_fn}, FutureExt, Sink, Stream};
+use std::{rc::Rc, sync::OnceLock, thread};
+
+pub struct SmolRt;
+
+/*───────────────── executors ────────────────────────*/
+
+static GLOBAL: Executor<'static> = Executor::new();
+
+/// Size of the worker pool, fixed by whoever starts it first.
+static WORKERS: OnceLock<usize> = OnceLock::new();
+
+thread_local! {
+    static LOCAL: Rc<LocalExecutor<'static>> = Rc::new(LocalExecutor::new());
+}
+
+fn start_workers(n: usize) -> usize {
+    for i in 0..n {
+        thread::Builder::new()
+            .name(format!("everywhere-smol-{i}"))
+            .spawn(|| async_io::block_on(GLOBAL.run(pending::<()>())))
+            .expect("failed to spawn an executor thread");
+    }
+    n
+}
+
+fn global() -> &'static Executor<'static> {
+    WORKERS.get_or_init(|| {
+        start_workers(thread::available_parallelism().map_or(1, |n| n.get()))
+    });
+    &GLOBAL
+}
+
+/// Drive `fut` on this thread, helping with spawned and local tasks.
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let local = LOCAL.with(Rc::clone);
+    async_io::block_on(GLOBAL.run(local.run(fut)))
+}
+
+/// Entry point behind `#[main]`: `multi_thread` sizes the pool (unless a
+/// spawn already started it), `current_thread` keeps every task here.
+pub fn enter<F: Future>(multi_thread: bool, workers: Option<usize>, fut: F) -> F::Output {
+    WORKERS.get_or_init(|| match (multi_thread, workers) {
+        (false, _)      => 0,
+        (true, Some(n)) => start_workers(n),
+        (true, None)    => start_workers(thread::available_parallelism().map_or(1, |n| n.get())),
+    });
+    block_on(fut)
+}
+
+/*───────────────── implementation ───────────────────*/
+
+impl Runtime for SmolRt {
+    type Sender<T: 'static + MaybeSend> = Sender<T>;
+    type Receiver<T: 'static + MaybeSend> = Receiver<T>;
+    type BoundedSender<T: 'static + MaybeSend> = BoundedSender<T>;
+    type BoundedReceiver<T: 'static + MaybeSend> = Receiver<T>;
+    type OneshotSender<T: 'static + MaybeSend> = OneshotSender<T>;
+    type OneshotReceiver<T: 'static + MaybeSend> = OneshotReceiver<T>;
+    type BroadcastSender<T: 'static + MaybeSend + Clone> = broadcast::Sender<T>;
+    type BroadcastReceiver<T: 'static + MaybeSend + Clone> = broadcast::Receiver<T>;
+    type WatchSender<T: 'static + MaybeSend> = watch::Sender<T>;
+    type WatchReceiver<T: 'static + MaybeSend> = watch::Receiver<T>;
+
+    fn spawn<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
+    {
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        global().spawn(fut).detach();
+        handle
+    }
+
+    fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: Future + 'static,
+        F::Output: 'static,
+    {
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        LOCAL.with(|ex| ex.spawn(fut).detach());
+        handle
+    }
+
+    fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        /* as on Tokio: hooks and task‑locals wrap the closure itself,
+         * not just the future waiting for the pool */
+        let hooked = crate::hook::wrap(async move { f() });
+        let (fut, handle) = JoinHandle::remote(blocking::unblock(move || {
+            hooked.now_or_never().expect("a blocking body finishes in one poll")
+        }));
+        global().spawn(fut).detach();
+        handle
+    }
+
+    async fn run_local<F: Future>(fut: F) -> F::Output {
+        let local = LOCAL.with(Rc::clone);
+        local.run(fut).await
+    }
+
+    fn channel<T: 'static + MaybeSend>()
+        -> (Self::Sender<T>, Self::Receiver<T>)
+    {
+        let (tx, rx) = async_channel::unbounded();
+        let depth = Depth::new(None);
+        (Sender(tx, depth.clone()), Receiver(Box::pin(rx), depth))
+    }
+
+    fn channel_bounded<T: 'static + MaybeSend>(cap: usize)
+        -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>)
+    {
+        assert!(cap > 0, "bounded channel requires cap > 0");
+        let (tx, rx) = async_channel::bounded(cap);
+        let depth = Depth::new(Some(cap));
+        (BoundedSender(tx, depth.clone()), Receiver(Box::pin(rx), depth))
+    }
+
+    fn oneshot<T: 'static + MaybeSend>()
+        -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>)
+    { portable::oneshot() }
+
+    fn broadcast<T: 'static + MaybeSend + Clone>(cap: usize)
+        -> (Self::BroadcastSender<T>, Self::BroadcastReceiver<T>)
+    { broadcast::channel(cap) }
+
+    fn watch<T: 'static + MaybeSend>(init: T)
+        -> (Self::WatchSender<T>, Self::WatchReceiver<T>)
+    { watch::channel(init) }
+
+    type Sleep = Sleep;
+
+    fn sleep(d: Duration) -> Self::Sleep { Sleep(async_io::Timer::after(d)) }
+
+    async fn yield_now() {
+        let mut yielded = false;
+        poll_fn(|cx| {
+            if yielded { return Poll::Ready(()); }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+            .await
+    }
+}
+
+/*───────────────── sleep ────────────────────────────*/
+
+/// `async_io::Timer` with the `()` output every backend shares.
+#[must_use = "futures do nothing unless polled"]
+pub struct Sleep(async_io::Timer);
+
+impl Future for Sleep {
+    type Output = ();
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.0).poll(cx).map(drop)
+    }
+}
+
+/*───────────────── channels ─────────────────────────*/
+
+/// `async_channel::Sender` of an unbounded channel.
+pub struct Sender<T>(async_channel::Sender<T>, Depth);
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+impl<T: 'static + MaybeSend> SendMessage<T> for Sender<T> {
+    fn try_send(&self, v: T) -> Result<(), SendError<T>> {
+        self.1.sent(self.0.try_send(v).map_err(|e| SendError(e.into_inner())))
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = Closed;
+
+    fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(if self.0.is_closed() { Err(Closed) } else { Ok(()) })
+    }
+    fn start_send(self: Pin<&mut Self>, v: T) -> Result<(), Closed> {
+        self.1.sent(self.0.try_send(v).map_err(|_| Closed))
+    }
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Closed>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// `async_channel::Sender` behind the crate‑wide [`BoundedSendMessage`] API.
+pub struct BoundedSender<T>(async_channel::Sender<T>, Depth);
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone()) }
+}
+
+impl<T: 'static + MaybeSend> BoundedSendMessage<T> for BoundedSender<T> {
+    async fn send(&mut self, v: T) -> Result<(), SendError<T>> {
+        self.1.sent(self.0.send(v).await.map_err(|e| SendError(e.0)))
+    }
+
+    fn try_send(&mut self, v: T) -> Result<(), TrySendError<T>> {
+        use async_channel::TrySendError as Smol;
+        self.1.sent(self.0.try_send(v).map_err(|e| match e {
+            Smol::Full(v)   => TrySendError::Full(v),
+            Smol::Closed(v) => TrySendError::Closed(v),
+        }))
+    }
+}
+
+/// Receiving half of both channel flavours, behind [`ReceiverExt`] and `Stream`.
+/// Boxed because `async_channel::Receiver` is `!Unpin`.
+pub struct Receiver<T>(Pin<Box<async_channel::Receiver<T>>>, Depth);
+
+impl<T: 'static + MaybeSend> ReceiverExt<T> for Receiver<T> {
+    async fn recv(&mut self) -> Option<T> { self.1.recv(self.0.recv().await.ok()) }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        self.1.try_recv(self.0.try_recv().map_err(|e| match e {
+            async_channel::TryRecvError::Empty  => TryRecvError::Empty,
+            async_channel::TryRecvError::Closed => TryRecvError::Closed,
+        }))
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = &mut *self;
+        this.1.poll(this.0.as_mut().poll_next(cx))
+    }
+}
@@ -0,0 +1,82 @@
+//! async‑std (WASI)
+
+use super::portable::{
+    self, broadcast, watch, BoundedReceiver, BoundedSender, OneshotReceiver, OneshotSender, Receiver, Sender,
+};
+use crate::{api::*, handle::JoinHandle};
+use async_std::task;
+use core::{future::Future, pin::Pin, time::Duration};
+
+pub struct WasiRt;
+
+pub fn block_on<F: Future>(fut: F) -> F::Output { task::block_on(fut) }
+
+/*───────────────── implementation ───────────────────*/
+
+impl Runtime for WasiRt {
+    type Sender<T: 'static + MaybeSend> = Sender<T>;
+    type Receiver<T: 'static + MaybeSend> = Receiver<T>;
+    type BoundedSender<T: 'static + MaybeSend> = BoundedSender<T>;
+    type BoundedReceiver<T: 'static + MaybeSend> = BoundedReceiver<T>;
+    type OneshotSender<T: 'static + MaybeSend> = OneshotSender<T>;
+    type OneshotReceiver<T: 'static + MaybeSend> = OneshotReceiver<T>;
+    type BroadcastSender<T: 'static + MaybeSend + Clone> = broadcast::Sender<T>;
+    type BroadcastReceiver<T: 'static + MaybeSend + Clone> = broadcast::Receiver<T>;
+    type WatchSender<T: 'static + MaybeSend> = watch::Sender<T>;
+    type WatchReceiver<T: 'static + MaybeSend> = watch::Receiver<T>;
+
+    fn spawn<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: core::future::Future + MaybeSend + 'static,
+        F::Output: MaybeSend + 'static,
+    {
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        task::spawn_local(fut);
+        handle
+    }
+
+    fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+    where
+        F: core::future::Future + 'static,
+        F::Output: 'static,
+    {
+        let (fut, handle) = JoinHandle::remote(crate::hook::wrap(fut));
+        task::spawn_local(fut);
+        handle
+    }
+
+    fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+    where
+        F: FnOnce() -> T + MaybeSend + 'static,
+        T: MaybeSend + 'static,
+    {
+        /* no threads to hand it to: run inline as its own task */
+        Self::spawn_local(async move { f() })
+    }
+
+    fn channel<T: 'static + MaybeSend>()
+        -> (Self::Sender<T>, Self::Receiver<T>)
+    { portable::unbounded() }
+
+    fn channel_bounded<T: 'static + MaybeSend>(cap: usize)
+        -> (Self::BoundedSender<T>, Self::BoundedReceiver<T>)
+    { portable::bounded(cap) }
+
+    fn oneshot<T: 'static + MaybeSend>()
+        -> (Self::OneshotSender<T>, Self::OneshotReceiver<T>)
+    { portable::oneshot() }
+
+    fn broadcast<T: 'static + MaybeSend + Clone>(cap: usize)
+        -> (Self::BroadcastSender<T>, Self::BroadcastReceiver<T>)
+    { broadcast::channel(cap) }
+
+    fn watch<T: 'static + MaybeSend>(init: T)
+        -> (Self::WatchSender<T>, Self::WatchReceiver<T>)
+    { watch::channel(init) }
+
+    type Sleep = Pin<Box<dyn Future<Output = ()>>>;
+
+    fn sleep(d: Duration) -> Self::Sleep { Box::pin(task::sleep(d)) }
+
+    async fn yield_now() { task::yield_now().await }
+}
@@ -0,0 +1,58 @@
+//! Browser: no process signals, but the page tells us when it goes away.
+
+use super::Shutdown;
+use core::{
+    cell::{Cell, RefCell},
+    future::pending,
+};
+use futures_channel::oneshot;
+use std::io;
+use wasm_bindgen::{closure::Closure, JsValue};
+
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = globalThis, js_name = addEventListener)]
+    fn add_event_listener(kind: &str, listener: &JsValue);
+}
+
+thread_local! {
+    /// Callers parked in [`page_hide`]; the listeners wake them all.
+    static WAITERS: RefCell<Vec<oneshot::Sender<()>>> = const { RefCell::new(Vec::new()) };
+    static LISTENING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Never completes: a page has no Ctrl‑C.
+pub async fn ctrl_c() -> io::Result<()> { pending().await }
+
+/// Completes on the next `pagehide` or `beforeunload` event.
+///
+/// The page may be frozen right after; keep the clean‑up synchronous
+/// or short (e.g. `navigator.sendBeacon`).
+pub async fn page_hide() {
+    listen();
+    let (tx, rx) = oneshot::channel::<()>();
+    WAITERS.with(|w| {
+        let mut w = w.borrow_mut();
+        w.retain(|tx| !tx.is_canceled());       /* callers that gave up */
+        w.push(tx);
+    });
+    let _ = rx.await;
+}
+
+/// Put the two listeners on `globalThis`, once per thread; they live as
+/// long as the page.
+fn listen() {
+    if LISTENING.with(|l| l.replace(true)) { return; }
+    for kind in ["pagehide", "beforeunload"] {
+        let listener = Closure::<dyn FnMut()>::new(|| {
+            for tx in WAITERS.with(|w| w.take()) { let _ = tx.send(()); }
+        });
+        add_event_listener(kind, &listener.into_js_value());
+    }
+}
+
+/// Completes when the page is hidden or unloaded.
+pub async fn shutdown() -> Shutdown {
+    page_hide().await;
+    Shutdown::PageHide
+}
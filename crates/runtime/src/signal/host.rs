@@ -0,0 +1,112 @@
+//! Native signals: Tokio's `signal` module, or `async-signal` under smol.
+
+use super::Shutdown;
+use core::{
+    future::{pending, Future},
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+use futures_util::{future::poll_fn, Stream, StreamExt};
+use std::io;
+
+/// Completes on the next Ctrl‑C (SIGINT).
+#[cfg(not(feature = "smol"))]
+pub async fn ctrl_c() -> io::Result<()> { tokio::signal::ctrl_c().await }
+#[cfg(feature = "smol")]
+pub async fn ctrl_c() -> io::Result<()> {
+    let mut sigs = async_signal::Signals::new([async_signal::Signal::Int])?;
+    sigs.next().await.map_or(Ok(()), |r| r.map(drop))
+}
+
+/// Every SIGTERM from now on.
+#[cfg(unix)]
+pub fn sigterm() -> io::Result<SignalStream> { SignalStream::new(Kind::Term) }
+
+/// Every SIGHUP from now on.
+#[cfg(unix)]
+pub fn sighup() -> io::Result<SignalStream> { SignalStream::new(Kind::Hup) }
+
+/// First of Ctrl‑C, SIGTERM or SIGHUP (unix).
+///
+/// A signal whose handler cannot be installed is left out.
+pub async fn shutdown() -> Shutdown {
+    let mut ctrl_c = pin!(async {
+        if ctrl_c().await.is_err() { pending::<()>().await }
+    });
+    #[cfg(unix)]
+    let (mut term, mut hup) = (sigterm().ok(), sighup().ok());
+
+    poll_fn(|cx| {
+        if ctrl_c.as_mut().poll(cx).is_ready() { return Poll::Ready(Shutdown::CtrlC); }
+        #[cfg(unix)]
+        {
+            if let Some(Poll::Ready(Some(()))) = term.as_mut().map(|s| s.poll_next_unpin(cx)) {
+                return Poll::Ready(Shutdown::Terminate);
+            }
+            if let Some(Poll::Ready(Some(()))) = hup.as_mut().map(|s| s.poll_next_unpin(cx)) {
+                return Poll::Ready(Shutdown::Hangup);
+            }
+        }
+        Poll::Pending
+    })
+        .await
+}
+
+/*──────────────────────── stream ──────────────────────────────*/
+
+#[cfg(unix)]
+enum Kind { Term, Hup }
+
+/// Yields `()` each time the signal arrives.
+#[cfg(unix)]
+pub struct SignalStream {
+    #[cfg(not(feature = "smol"))]
+    inner: tokio::signal::unix::Signal,
+    #[cfg(feature = "smol")]
+    inner: async_signal::Signals,
+}
+
+#[cfg(unix)]
+impl SignalStream {
+    #[cfg(not(feature = "smol"))]
+    fn new(kind: Kind) -> io::Result<Self> {
+        use tokio::signal::unix::{signal, SignalKind};
+        let kind = match kind {
+            Kind::Term => SignalKind::terminate(),
+            Kind::Hup  => SignalKind::hangup(),
+        };
+        Ok(Self { inner: signal(kind)? })
+    }
+
+    #[cfg(feature = "smol")]
+    fn new(kind: Kind) -> io::Result<Self> {
+        use async_signal::{Signal, Signals};
+        let sig = match kind {
+            Kind::Term => Signal::Term,
+            Kind::Hup  => Signal::Hup,
+        };
+        Ok(Self { inner: Signals::new([sig])? })
+    }
+}
+
+#[cfg(unix)]
+impl Stream for SignalStream {
+    type Item = ();
+
+    #[cfg(not(feature = "smol"))]
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_recv(cx)
+    }
+
+    #[cfg(feature = "smol")]
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<()>> {
+        self.inner.poll_next_unpin(cx).map(|s| s.and_then(Result::ok).map(drop))
+    }
+}
+
+#[cfg(unix)]
+impl core::fmt::Debug for SignalStream {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SignalStream { .. }")
+    }
+}
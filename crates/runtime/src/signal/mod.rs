@@ -0,0 +1,55 @@
+//! Shutdown signals on every target.
+//!
+//! | backend | [`ctrl_c`] | [`sigterm`] / [`sighup`] | [`shutdown`] fires on        |
+//! |---------|------------|--------------------------|------------------------------|
+//! | native  | SIGINT     | unix only                | Ctrl‑C, SIGTERM, SIGHUP      |
+//! | smol    | SIGINT     | unix only                | Ctrl‑C, SIGTERM, SIGHUP      |
+//! | browser | never      | –                        | `pagehide` / `beforeunload`  |
+//! | WASI    | never      | –                        | never (no signals in WASI)   |
+//!
+//! ```ignore
+//! select! {
+//!     _      = serve().fuse()              => {}
+//!     reason = signal::shutdown().fuse()   => log::info!("stopping: {reason:?}"),
+//! }
+//! ```
+
+use core::fmt;
+
+/// Why [`shutdown`] completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Shutdown {
+    /// Ctrl‑C / SIGINT.
+    CtrlC,
+    /// SIGTERM.
+    Terminate,
+    /// SIGHUP.
+    Hangup,
+    /// The page is being hidden or unloaded.
+    PageHide,
+}
+
+impl fmt::Display for Shutdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::CtrlC     => "ctrl-c",
+            Self::Terminate => "SIGTERM",
+            Self::Hangup    => "SIGHUP",
+            Self::PageHide  => "page hidden",
+        })
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(all(any(feature = "smol", feature = "native"), not(target_arch = "wasm32")))] {
+        mod host;
+        pub use host::*;
+    } else if #[cfg(all(feature = "browser", target_arch = "wasm32"))] {
+        mod browser;
+        pub use browser::*;
+    } else {
+        mod wasi;
+        pub use wasi::*;
+    }
+}
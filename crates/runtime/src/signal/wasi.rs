@@ -0,0 +1,11 @@
+//! WASI has no signals yet; everything here waits forever.
+
+use super::Shutdown;
+use core::future::pending;
+use std::io;
+
+/// Never completes: WASI delivers no signals.
+pub async fn ctrl_c() -> io::Result<()> { pending().await }
+
+/// Never completes; the host simply stops the module.
+pub async fn shutdown() -> Shutdown { pending().await }
@@ -0,0 +1,160 @@
+//! Hierarchical cancellation, independent of the executor.
+//!
+//! Cloned tokens share one state; [`CancellationToken::child_token`]
+//! creates a new node that is cancelled together with its parent but can
+//! also be cancelled on its own.
+
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+use futures_util::future::{select, Either};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard, Weak},
+};
+
+#[derive(Default)]
+struct Node {
+    cancelled: AtomicBool,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    waiters: BTreeMap<u64, Waker>,
+    children: Vec<Weak<Node>>,
+}
+
+impl Node {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn is_cancelled(&self) -> bool { self.cancelled.load(Ordering::Acquire) }
+
+    fn cancel(&self) {
+        if self.cancelled.swap(true, Ordering::AcqRel) { return; }
+        let (waiters, children) = {
+            let mut st = self.lock();
+            (core::mem::take(&mut st.waiters), core::mem::take(&mut st.children))
+        };
+        waiters.into_values().for_each(Waker::wake);
+        children.iter().filter_map(Weak::upgrade).for_each(|c| c.cancel());
+    }
+}
+
+/*──────────────────────── token ───────────────────────────────*/
+
+/// Cheap, clonable “please stop” signal.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    node: Arc<Node>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self { Self::default() }
+
+    /// Cancel this token and every descendant; idempotent.
+    pub fn cancel(&self) { self.node.cancel(); }
+
+    pub fn is_cancelled(&self) -> bool { self.node.is_cancelled() }
+
+    /// New token cancelled whenever `self` is – but not vice versa.
+    pub fn child_token(&self) -> Self {
+        let child = Arc::new(Node::default());
+        let mut st = self.node.lock();
+        if self.node.is_cancelled() {
+            child.cancelled.store(true, Ordering::Release);
+        } else {
+            st.children.retain(|w| w.strong_count() > 0);
+            st.children.push(Arc::downgrade(&child));
+        }
+        Self { node: child }
+    }
+
+    /// Future that resolves once the token is cancelled.
+    ///
+    /// It owns a handle to the token, so it can be moved into a task.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled { node: self.node.clone(), id: None }
+    }
+
+    /// Drive `fut` until it finishes (`Some`) or the token fires (`None`).
+    /// Cancellation wins if both are ready at once.
+    pub async fn run_until_cancelled<F: Future>(&self, fut: F) -> Option<F::Output> {
+        futures_util::pin_mut!(fut);
+        match select(self.cancelled(), fut).await {
+            Either::Left(_)       => None,
+            Either::Right((v, _)) => Some(v),
+        }
+    }
+
+    /// Guard that cancels the token when dropped.
+    pub fn drop_guard(self) -> DropGuard { DropGuard { token: Some(self) } }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/*──────────────────────── cancelled() ─────────────────────────*/
+
+/// Future returned by [`CancellationToken::cancelled`].
+pub struct Cancelled {
+    node: Arc<Node>,
+    id: Option<u64>,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.node.is_cancelled() { return Poll::Ready(()); }
+
+        let node = self.node.clone();
+        let mut st = node.lock();
+        /* re‑check under the lock: `cancel` drains waiters after flagging */
+        if node.is_cancelled() { return Poll::Ready(()); }
+        let id = *self.id.get_or_insert_with(|| {
+            st.next_id += 1;
+            st.next_id
+        });
+        st.waiters.insert(id, cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Some(id) = self.id { self.node.lock().waiters.remove(&id); }
+    }
+}
+
+/*──────────────────────── drop guard ──────────────────────────*/
+
+/// Cancels its token on drop unless [`disarm`](Self::disarm)ed.
+pub struct DropGuard {
+    token: Option<CancellationToken>,
+}
+
+impl DropGuard {
+    /// Give the token back without cancelling it.
+    pub fn disarm(mut self) -> CancellationToken {
+        self.token.take().expect("token present until drop")
+    }
+}
+
+impl Drop for DropGuard {
+    fn drop(&mut self) {
+        if let Some(t) = self.token.take() { t.cancel(); }
+    }
+}
@@ -0,0 +1,39 @@
+//! Executor‑agnostic coordination primitives.
+//!
+//! The async locks are Tokio's own on native; browser and WASI get
+//! executor‑agnostic twins with the same API (FIFO‑fair, built on one
+//! [`Semaphore`]), down to `const_new`, `blocking_lock`, mapped guards
+//! and [`Notified::enable`]. [`RwLock`] wraps Tokio's so both expose
+//! exactly the same methods.
+
+mod cancel;
+mod rwlock;
+
+pub use cancel::{Cancelled, CancellationToken, DropGuard};
+pub use rwlock::{
+    OwnedRwLockReadGuard, OwnedRwLockWriteGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "native", not(target_arch = "wasm32")))] {
+        pub use tokio::sync::{
+            AcquireError, MappedMutexGuard, Mutex, MutexGuard, Notify, OwnedMappedMutexGuard,
+            OwnedMutexGuard, OwnedSemaphorePermit, Semaphore, SemaphorePermit, TryAcquireError,
+            TryLockError,
+        };
+        pub use tokio::sync::futures::Notified;
+    } else {
+        mod mutex;
+        mod notify;
+        mod semaphore;
+
+        pub use mutex::{
+            MappedMutexGuard, Mutex, MutexGuard, OwnedMappedMutexGuard, OwnedMutexGuard,
+            TryLockError,
+        };
+        pub use notify::{Notified, Notify};
+        pub use semaphore::{
+            AcquireError, OwnedSemaphorePermit, Semaphore, SemaphorePermit, TryAcquireError,
+        };
+    }
+}
@@ -0,0 +1,333 @@
+//! Async mutex: a one‑permit [`Semaphore`] guarding an `UnsafeCell`.
+
+use super::semaphore::{Semaphore, TryAcquireError};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    pin::pin,
+    ptr,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    sync::Arc,
+    task::Wake,
+    thread::{self, Thread},
+};
+
+/// `try_lock`/`try_read`/`try_write` found the lock taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryLockError(());
+
+impl fmt::Display for TryLockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("operation would block") }
+}
+
+impl std::error::Error for TryLockError {}
+
+impl From<TryAcquireError> for TryLockError {
+    fn from(_: TryAcquireError) -> Self { Self(()) }
+}
+
+/// Mutex whose guard may be held across `.await`; waiters are served
+/// in FIFO order.
+pub struct Mutex<T: ?Sized> {
+    sem: Semaphore,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: the semaphore hands out at most one guard at a time.
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    pub fn new(value: T) -> Self { Self::const_new(value) }
+
+    /// `new` for `static`s.
+    pub const fn const_new(value: T) -> Self {
+        Self { sem: Semaphore::const_new(1), value: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T { self.value.into_inner() }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    pub async fn lock(&self) -> MutexGuard<'_, T> {
+        /* never closed, so acquiring cannot fail */
+        self.sem.acquire().await.unwrap_or_else(|_| unreachable!()).forget();
+        MutexGuard { lock: self }
+    }
+
+    pub fn try_lock(&self) -> Result<MutexGuard<'_, T>, TryLockError> {
+        self.sem.try_acquire()?.forget();
+        Ok(MutexGuard { lock: self })
+    }
+
+    /// Like [`lock`](Self::lock), but the guard owns an `Arc` of the mutex.
+    pub async fn lock_owned(self: Arc<Self>) -> OwnedMutexGuard<T> {
+        self.sem.acquire().await.unwrap_or_else(|_| unreachable!()).forget();
+        OwnedMutexGuard { lock: self }
+    }
+
+    pub fn try_lock_owned(self: Arc<Self>) -> Result<OwnedMutexGuard<T>, TryLockError> {
+        self.sem.try_acquire()?.forget();
+        Ok(OwnedMutexGuard { lock: self })
+    }
+
+    /// Lock from synchronous code, parking the thread until it is free.
+    ///
+    /// As with Tokio, never call this from async code. A single‑threaded
+    /// wasm host has no one else to release the lock, so there it only
+    /// returns when the lock is free already.
+    pub fn blocking_lock(&self) -> MutexGuard<'_, T> { park_on(self.lock()) }
+
+    pub fn blocking_lock_owned(self: Arc<Self>) -> OwnedMutexGuard<T> { park_on(self.lock_owned()) }
+
+    /// No locking needed: `&mut self` proves exclusive access.
+    pub fn get_mut(&mut self) -> &mut T { self.value.get_mut() }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self { Self::new(T::default()) }
+}
+
+impl<T> From<T> for Mutex<T> {
+    fn from(value: T) -> Self { Self::new(value) }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("Mutex");
+        match self.try_lock() {
+            Ok(g)  => d.field("data", &&*g),
+            Err(_) => d.field("data", &format_args!("<locked>")),
+        };
+        d.finish()
+    }
+}
+
+/// Poll `fut` on this thread, parking between wake‑ups.
+fn park_on<F: Future>(fut: F) -> F::Output {
+    struct Unpark(Thread);
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) { self.0.unpark(); }
+    }
+
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = pin!(fut);
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) { return out; }
+        thread::park();
+    }
+}
+
+/*──────────────────────── guards ──────────────────────────────*/
+
+/// Borrowed lock; unlocks on drop.
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct MutexGuard<'a, T: ?Sized> {
+    lock: &'a Mutex<T>,
+}
+
+// SAFETY: a shared guard only hands out `&T`.
+unsafe impl<T: ?Sized + Send + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<'a, T: ?Sized> MutexGuard<'a, T> {
+    /// Narrow the guard to a part of the value; the lock stays held.
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> MappedMutexGuard<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let this = ManuallyDrop::new(this);
+        // SAFETY: we hold the only permit, which moves to the new guard.
+        let data = f(unsafe { &mut *this.lock.value.get() });
+        MappedMutexGuard { sem: &this.lock.sem, data, _value: PhantomData }
+    }
+
+    /// [`map`](Self::map), or the guard back if `f` declines.
+    pub fn try_map<U: ?Sized, F>(this: Self, f: F) -> Result<MappedMutexGuard<'a, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        // SAFETY: as in `map`; on `None` the guard keeps its permit.
+        match f(unsafe { &mut *this.lock.value.get() }) {
+            Some(data) => {
+                let this = ManuallyDrop::new(this);
+                Ok(MappedMutexGuard { sem: &this.lock.sem, data, _value: PhantomData })
+            }
+            None => Err(this),
+        }
+    }
+
+    pub fn mutex(this: &Self) -> &'a Mutex<T> { this.lock }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) { self.lock.sem.add_permits(1); }
+}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+    // SAFETY: holding the guard means holding the only permit.
+    fn deref(&self) -> &T { unsafe { &*self.lock.value.get() } }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T { unsafe { &mut *self.lock.value.get() } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+/// Lock that keeps its mutex alive; movable into spawned tasks.
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct OwnedMutexGuard<T: ?Sized> {
+    lock: Arc<Mutex<T>>,
+}
+
+unsafe impl<T: ?Sized + Send + Sync> Sync for OwnedMutexGuard<T> {}
+
+impl<T: ?Sized> OwnedMutexGuard<T> {
+    /// Narrow the guard to a part of the value; the lock stays held.
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> OwnedMappedMutexGuard<T, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        // SAFETY: we hold the only permit; `lock` moves out exactly once.
+        let data: *mut U = f(unsafe { &mut *this.lock.value.get() });
+        let lock = unsafe { ptr::read(&ManuallyDrop::new(this).lock) };
+        OwnedMappedMutexGuard { lock, data }
+    }
+
+    /// [`map`](Self::map), or the guard back if `f` declines.
+    pub fn try_map<U: ?Sized, F>(this: Self, f: F) -> Result<OwnedMappedMutexGuard<T, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        let Some(data) = f(unsafe { &mut *this.lock.value.get() }) else { return Err(this) };
+        let data: *mut U = data;
+        let lock = unsafe { ptr::read(&ManuallyDrop::new(this).lock) };
+        Ok(OwnedMappedMutexGuard { lock, data })
+    }
+
+    pub fn mutex(this: &Self) -> &Arc<Mutex<T>> { &this.lock }
+}
+
+impl<T: ?Sized> Drop for OwnedMutexGuard<T> {
+    fn drop(&mut self) { self.lock.sem.add_permits(1); }
+}
+
+impl<T: ?Sized> Deref for OwnedMutexGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &T { unsafe { &*self.lock.value.get() } }
+}
+
+impl<T: ?Sized> DerefMut for OwnedMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut T { unsafe { &mut *self.lock.value.get() } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedMutexGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+/// A [`MutexGuard`] narrowed by [`MutexGuard::map`].
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct MappedMutexGuard<'a, T: ?Sized> {
+    sem: &'a Semaphore,
+    data: *mut T,
+    _value: PhantomData<&'a mut T>,
+}
+
+// SAFETY: the guard is a `&mut T` plus the permit that makes it unique.
+unsafe impl<T: ?Sized + Send> Send for MappedMutexGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for MappedMutexGuard<'_, T> {}
+
+impl<'a, T: ?Sized> MappedMutexGuard<'a, T> {
+    pub fn map<U: ?Sized, F>(this: Self, f: F) -> MappedMutexGuard<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        let this = ManuallyDrop::new(this);
+        let data = f(unsafe { &mut *{ this.data } });
+        MappedMutexGuard { sem: this.sem, data, _value: PhantomData }
+    }
+
+    pub fn try_map<U: ?Sized, F>(this: Self, f: F) -> Result<MappedMutexGuard<'a, U>, Self>
+    where
+        F: FnOnce(&mut T) -> Option<&mut U>,
+    {
+        let Some(data) = f(unsafe { &mut *this.data }) else { return Err(this) };
+        let this = ManuallyDrop::new(this);
+        Ok(MappedMutexGuard { sem: this.sem, data, _value: PhantomData })
+    }
+}
+
+impl<T: ?Sized> Drop for MappedMutexGuard<'_, T> {
+    fn drop(&mut self) { self.sem.add_permits(1); }
+}
+
+impl<T: ?Sized> Deref for MappedMutexGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T { unsafe { &*self.data } }
+}
+
+impl<T: ?Sized> DerefMut for MappedMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T { unsafe { &mut *self.data } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MappedMutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+/// An [`OwnedMutexGuard`] narrowed by [`OwnedMutexGuard::map`].
+#[must_use = "if unused the Mutex will immediately unlock"]
+pub struct OwnedMappedMutexGuard<T: ?Sized, U: ?Sized = T> {
+    lock: Arc<Mutex<T>>,
+    data: *mut U,
+}
+
+unsafe impl<T: ?Sized + Send, U: ?Sized + Send> Send for OwnedMappedMutexGuard<T, U> {}
+unsafe impl<T: ?Sized + Send + Sync, U: ?Sized + Send + Sync> Sync for OwnedMappedMutexGuard<T, U> {}
+
+impl<T: ?Sized, U: ?Sized> OwnedMappedMutexGuard<T, U> {
+    pub fn map<S: ?Sized, F>(this: Self, f: F) -> OwnedMappedMutexGuard<T, S>
+    where
+        F: FnOnce(&mut U) -> &mut S,
+    {
+        let data: *mut S = f(unsafe { &mut *this.data });
+        let lock = unsafe { ptr::read(&ManuallyDrop::new(this).lock) };
+        OwnedMappedMutexGuard { lock, data }
+    }
+
+    pub fn try_map<S: ?Sized, F>(this: Self, f: F) -> Result<OwnedMappedMutexGuard<T, S>, Self>
+    where
+        F: FnOnce(&mut U) -> Option<&mut S>,
+    {
+        let Some(data) = f(unsafe { &mut *this.data }) else { return Err(this) };
+        let data: *mut S = data;
+        let lock = unsafe { ptr::read(&ManuallyDrop::new(this).lock) };
+        Ok(OwnedMappedMutexGuard { lock, data })
+    }
+}
+
+impl<T: ?Sized, U: ?Sized> Drop for OwnedMappedMutexGuard<T, U> {
+    fn drop(&mut self) { self.lock.sem.add_permits(1); }
+}
+
+impl<T: ?Sized, U: ?Sized> Deref for OwnedMappedMutexGuard<T, U> {
+    type Target = U;
+    fn deref(&self) -> &U { unsafe { &*self.data } }
+}
+
+impl<T: ?Sized, U: ?Sized> DerefMut for OwnedMappedMutexGuard<T, U> {
+    fn deref_mut(&mut self) -> &mut U { unsafe { &mut *self.data } }
+}
+
+impl<T: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for OwnedMappedMutexGuard<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
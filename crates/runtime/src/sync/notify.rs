@@ -0,0 +1,153 @@
+//! Task wake‑up without data, with Tokio's `Notify` semantics.
+//!
+//! * `notify_one` wakes the oldest waiter, or stores a single permit that
+//!   the next `notified().await` consumes at once;
+//! * `notify_waiters` wakes every [`Notified`] created before the call –
+//!   polled or not – and stores no permit.
+
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Mutex, MutexGuard},
+};
+
+#[derive(Default)]
+struct State {
+    permit: bool,
+    generation: u64,               /* bumped by `notify_waiters` */
+    next_id: u64,
+    waiters: BTreeMap<u64, Waker>, /* FIFO by id */
+    notified: BTreeSet<u64>,       /* picked by `notify_one`, not yet polled */
+}
+
+#[derive(Default)]
+pub struct Notify {
+    state: Mutex<State>,
+}
+
+impl Notify {
+    pub fn new() -> Self { Self::default() }
+
+    /// `new` for `static`s.
+    pub const fn const_new() -> Self {
+        Self {
+            state: Mutex::new(State {
+                permit: false,
+                generation: 0,
+                next_id: 0,
+                waiters: BTreeMap::new(),
+                notified: BTreeSet::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Future resolving on the next notification.
+    pub fn notified(&self) -> Notified<'_> {
+        Notified { notify: self, generation: self.lock().generation, id: None, done: false }
+    }
+
+    pub fn notify_one(&self) {
+        let mut st = self.lock();
+        match st.waiters.pop_first() {
+            Some((id, waker)) => {
+                st.notified.insert(id);
+                waker.wake();
+            }
+            None => st.permit = true,
+        }
+    }
+
+    pub fn notify_waiters(&self) {
+        let waiters = {
+            let mut st = self.lock();
+            st.generation += 1;
+            core::mem::take(&mut st.waiters)
+        };
+        waiters.into_values().for_each(Waker::wake);
+    }
+}
+
+impl fmt::Debug for Notify {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_struct("Notify").finish_non_exhaustive() }
+}
+
+/*──────────────────────── future ──────────────────────────────*/
+
+/// Returned by [`Notify::notified`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Notified<'a> {
+    notify: &'a Notify,
+    generation: u64,
+    id: Option<u64>,
+    done: bool,
+}
+
+impl Future for Notified<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.get_mut().register(Some(cx.waker())) { Poll::Ready(()) } else { Poll::Pending }
+    }
+}
+
+impl Notified<'_> {
+    /// Join the queue without polling, so a `notify_one` can pick this
+    /// future; `true` if it is already notified.
+    pub fn enable(self: Pin<&mut Self>) -> bool { self.get_mut().register(None) }
+
+    /// Take a pending notification, or queue up with `waker` (a no‑op
+    /// waker when merely enabling, never replacing a real one).
+    fn register(&mut self, waker: Option<&Waker>) -> bool {
+        if self.done { return true; }
+        let mut st = self.notify.lock();
+
+        let woken = st.generation != self.generation || match self.id {
+            Some(id) => st.notified.remove(&id),
+            None     => core::mem::take(&mut st.permit),
+        };
+        if woken {
+            self.done = true;
+            drop(st);
+            self.release();
+            return true;
+        }
+
+        let id = *self.id.get_or_insert_with(|| {
+            st.next_id += 1;
+            st.next_id
+        });
+        match waker {
+            Some(w) => { st.waiters.insert(id, w.clone()); }
+            None    => { st.waiters.entry(id).or_insert_with(|| Waker::noop().clone()); }
+        }
+        false
+    }
+
+    /// Leave the queue; a `notify_one` still addressed to us moves on.
+    fn release(&mut self) {
+        let Some(id) = self.id.take() else { return };
+        let mut st = self.notify.lock();
+        st.waiters.remove(&id);
+        if st.notified.remove(&id) {
+            drop(st);
+            self.notify.notify_one();
+        }
+    }
+}
+
+impl Drop for Notified<'_> {
+    fn drop(&mut self) { self.release(); }
+}
+
+impl fmt::Debug for Notified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_struct("Notified").finish_non_exhaustive() }
+}
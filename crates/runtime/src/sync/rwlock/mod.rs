@@ -0,0 +1,11 @@
+//! Async reader‑writer lock with one method set on every target.
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "native", not(target_arch = "wasm32")))] {
+        mod native;
+        pub use native::*;
+    } else {
+        mod portable;
+        pub use portable::*;
+    }
+}
@@ -0,0 +1,109 @@
+//! Tokio's reader‑writer lock behind the portable method set.
+//!
+//! Tokio's own `RwLock` has more (`downgrade`, `map`, `blocking_*`, …);
+//! code that reached for those would stop compiling off native, so this
+//! newtype only passes through what [`portable`](super::portable) has.
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+use std::sync::Arc;
+use tokio::sync::{self as tk, TryLockError};
+
+#[repr(transparent)]
+pub struct RwLock<T: ?Sized>(tk::RwLock<T>);
+
+impl<T> RwLock<T> {
+    pub fn new(value: T) -> Self { Self(tk::RwLock::new(value)) }
+
+    pub fn into_inner(self) -> T { self.0.into_inner() }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    pub async fn read(&self) -> RwLockReadGuard<'_, T> { RwLockReadGuard(self.0.read().await) }
+
+    pub async fn write(&self) -> RwLockWriteGuard<'_, T> { RwLockWriteGuard(self.0.write().await) }
+
+    pub fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        self.0.try_read().map(RwLockReadGuard)
+    }
+
+    pub fn try_write(&self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        self.0.try_write().map(RwLockWriteGuard)
+    }
+
+    /// Like [`read`](Self::read), but the guard owns an `Arc` of the lock.
+    pub async fn read_owned(self: Arc<Self>) -> OwnedRwLockReadGuard<T> {
+        OwnedRwLockReadGuard(self.tokio().read_owned().await)
+    }
+
+    /// Like [`write`](Self::write), but the guard owns an `Arc` of the lock.
+    pub async fn write_owned(self: Arc<Self>) -> OwnedRwLockWriteGuard<T> {
+        OwnedRwLockWriteGuard(self.tokio().write_owned().await)
+    }
+
+    pub fn try_read_owned(self: Arc<Self>) -> Result<OwnedRwLockReadGuard<T>, TryLockError> {
+        self.tokio().try_read_owned().map(OwnedRwLockReadGuard)
+    }
+
+    pub fn try_write_owned(self: Arc<Self>) -> Result<OwnedRwLockWriteGuard<T>, TryLockError> {
+        self.tokio().try_write_owned().map(OwnedRwLockWriteGuard)
+    }
+
+    pub fn get_mut(&mut self) -> &mut T { self.0.get_mut() }
+
+    fn tokio(self: Arc<Self>) -> Arc<tk::RwLock<T>> {
+        // SAFETY: `repr(transparent)` – same layout and pointer metadata.
+        unsafe { Arc::from_raw(Arc::into_raw(self) as *const tk::RwLock<T>) }
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self { Self::new(T::default()) }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(value: T) -> Self { Self::new(value) }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&self.0, f) }
+}
+
+/*──────────────────────── guards ──────────────────────────────*/
+
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct RwLockReadGuard<'a, T: ?Sized>(tk::RwLockReadGuard<'a, T>);
+
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct RwLockWriteGuard<'a, T: ?Sized>(tk::RwLockWriteGuard<'a, T>);
+
+/// Read lock that keeps its `RwLock` alive; movable into spawned tasks.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct OwnedRwLockReadGuard<T: ?Sized>(tk::OwnedRwLockReadGuard<T>);
+
+/// Write lock that keeps its `RwLock` alive; movable into spawned tasks.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct OwnedRwLockWriteGuard<T: ?Sized>(tk::OwnedRwLockWriteGuard<T>);
+
+macro_rules! guard {
+    ($($guard:ty),*) => {$(
+        impl<T: ?Sized> Deref for $guard {
+            type Target = T;
+            fn deref(&self) -> &T { &self.0 }
+        }
+        impl<T: ?Sized + fmt::Debug> fmt::Debug for $guard {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+        }
+    )*};
+}
+guard!(RwLockReadGuard<'_, T>, RwLockWriteGuard<'_, T>, OwnedRwLockReadGuard<T>, OwnedRwLockWriteGuard<T>);
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T { &mut self.0 }
+}
+
+impl<T: ?Sized> DerefMut for OwnedRwLockWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut T { &mut self.0 }
+}
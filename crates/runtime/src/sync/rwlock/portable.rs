@@ -0,0 +1,192 @@
+//! Async reader‑writer lock.
+//!
+//! A reader takes one permit, a writer takes all of them; the FIFO
+//! semaphore underneath keeps a queued writer from being starved.
+
+use super::super::{mutex::TryLockError, semaphore::Semaphore};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+use std::sync::Arc;
+
+/// Concurrent readers an [`RwLock`] admits.
+const MAX_READS: u32 = u32::MAX >> 3;
+
+pub struct RwLock<T: ?Sized> {
+    sem: Semaphore,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: readers share `&T` (needs `Sync`), a writer gets `&mut T`
+// exclusively (needs `Send`).
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+impl<T> RwLock<T> {
+    pub fn new(value: T) -> Self {
+        Self { sem: Semaphore::new(MAX_READS as usize), value: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T { self.value.into_inner() }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    pub async fn read(&self) -> RwLockReadGuard<'_, T> {
+        self.sem.acquire().await.unwrap_or_else(|_| unreachable!()).forget();
+        RwLockReadGuard { lock: self }
+    }
+
+    pub async fn write(&self) -> RwLockWriteGuard<'_, T> {
+        self.sem.acquire_many(MAX_READS).await.unwrap_or_else(|_| unreachable!()).forget();
+        RwLockWriteGuard { lock: self }
+    }
+
+    pub fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        self.sem.try_acquire()?.forget();
+        Ok(RwLockReadGuard { lock: self })
+    }
+
+    pub fn try_write(&self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        self.sem.try_acquire_many(MAX_READS)?.forget();
+        Ok(RwLockWriteGuard { lock: self })
+    }
+
+    /// Like [`read`](Self::read), but the guard owns an `Arc` of the lock.
+    pub async fn read_owned(self: Arc<Self>) -> OwnedRwLockReadGuard<T> {
+        self.sem.acquire().await.unwrap_or_else(|_| unreachable!()).forget();
+        OwnedRwLockReadGuard { lock: self }
+    }
+
+    /// Like [`write`](Self::write), but the guard owns an `Arc` of the lock.
+    pub async fn write_owned(self: Arc<Self>) -> OwnedRwLockWriteGuard<T> {
+        self.sem.acquire_many(MAX_READS).await.unwrap_or_else(|_| unreachable!()).forget();
+        OwnedRwLockWriteGuard { lock: self }
+    }
+
+    pub fn try_read_owned(self: Arc<Self>) -> Result<OwnedRwLockReadGuard<T>, TryLockError> {
+        self.sem.try_acquire()?.forget();
+        Ok(OwnedRwLockReadGuard { lock: self })
+    }
+
+    pub fn try_write_owned(self: Arc<Self>) -> Result<OwnedRwLockWriteGuard<T>, TryLockError> {
+        self.sem.try_acquire_many(MAX_READS)?.forget();
+        Ok(OwnedRwLockWriteGuard { lock: self })
+    }
+
+    pub fn get_mut(&mut self) -> &mut T { self.value.get_mut() }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self { Self::new(T::default()) }
+}
+
+impl<T> From<T> for RwLock<T> {
+    fn from(value: T) -> Self { Self::new(value) }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("RwLock");
+        match self.try_read() {
+            Ok(g)  => d.field("data", &&*g),
+            Err(_) => d.field("data", &format_args!("<locked>")),
+        };
+        d.finish()
+    }
+}
+
+/*──────────────────────── guards ──────────────────────────────*/
+
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct RwLockReadGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+}
+
+// SAFETY: a read guard is just a shared `&T`.
+unsafe impl<T: ?Sized + Sync> Send for RwLockReadGuard<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+
+impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) { self.lock.sem.add_permits(1); }
+}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+    // SAFETY: no writer can hold all permits while this one is out.
+    fn deref(&self) -> &T { unsafe { &*self.lock.value.get() } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+}
+
+// SAFETY: a write guard is an exclusive `&mut T`.
+unsafe impl<T: ?Sized + Send + Sync> Send for RwLockWriteGuard<'_, T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLockWriteGuard<'_, T> {}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) { self.lock.sem.add_permits(MAX_READS as usize); }
+}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+    // SAFETY: the writer holds every permit.
+    fn deref(&self) -> &T { unsafe { &*self.lock.value.get() } }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T { unsafe { &mut *self.lock.value.get() } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for RwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+/// Read lock that keeps its `RwLock` alive; movable into spawned tasks.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct OwnedRwLockReadGuard<T: ?Sized> {
+    lock: Arc<RwLock<T>>,
+}
+
+impl<T: ?Sized> Drop for OwnedRwLockReadGuard<T> {
+    fn drop(&mut self) { self.lock.sem.add_permits(1); }
+}
+
+impl<T: ?Sized> Deref for OwnedRwLockReadGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &T { unsafe { &*self.lock.value.get() } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedRwLockReadGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+/// Write lock that keeps its `RwLock` alive; movable into spawned tasks.
+#[must_use = "if unused the RwLock will immediately unlock"]
+pub struct OwnedRwLockWriteGuard<T: ?Sized> {
+    lock: Arc<RwLock<T>>,
+}
+
+impl<T: ?Sized> Drop for OwnedRwLockWriteGuard<T> {
+    fn drop(&mut self) { self.lock.sem.add_permits(MAX_READS as usize); }
+}
+
+impl<T: ?Sized> Deref for OwnedRwLockWriteGuard<T> {
+    type Target = T;
+    fn deref(&self) -> &T { unsafe { &*self.lock.value.get() } }
+}
+
+impl<T: ?Sized> DerefMut for OwnedRwLockWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut T { unsafe { &mut *self.lock.value.get() } }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OwnedRwLockWriteGuard<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
@@ -0,0 +1,299 @@
+//! Fair (FIFO) counting semaphore – the base of the portable locks.
+//!
+//! A waiter at the head of the queue blocks everyone behind it, so a
+//! large `acquire_many` is never starved by a stream of small ones.
+
+use core::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/*──────────────────────── errors ──────────────────────────────*/
+
+/// The semaphore was closed while waiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcquireError(());
+
+impl fmt::Display for AcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("semaphore closed") }
+}
+
+impl std::error::Error for AcquireError {}
+
+/// Why a `try_acquire*` call failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryAcquireError {
+    Closed,
+    NoPermits,
+}
+
+impl TryAcquireError {
+    pub fn is_closed(&self) -> bool { matches!(self, Self::Closed) }
+    pub fn is_no_permits(&self) -> bool { matches!(self, Self::NoPermits) }
+}
+
+impl fmt::Display for TryAcquireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Closed    => f.write_str("semaphore closed"),
+            Self::NoPermits => f.write_str("no permits available"),
+        }
+    }
+}
+
+impl std::error::Error for TryAcquireError {}
+
+/*──────────────────────── state ───────────────────────────────*/
+
+struct Waiter {
+    needed: usize,
+    waker: Waker,
+}
+
+#[derive(Default)]
+struct State {
+    permits: usize,
+    closed: bool,
+    next_id: u64,
+    queue: BTreeMap<u64, Waiter>,    /* FIFO by id */
+    granted: BTreeSet<u64>,          /* served, not yet polled */
+}
+
+impl State {
+    /// Hand free permits to the head of the queue, in order.
+    fn dispatch(&mut self) {
+        while let Some(entry) = self.queue.first_entry() {
+            if entry.get().needed > self.permits { break; }
+            let (id, w) = entry.remove_entry();
+            self.permits -= w.needed;
+            self.granted.insert(id);
+            w.waker.wake();
+        }
+    }
+}
+
+/// Async counting semaphore.
+#[derive(Default)]
+pub struct Semaphore {
+    state: Mutex<State>,
+}
+
+impl Semaphore {
+    /// Largest number of permits a semaphore may hold.
+    pub const MAX_PERMITS: usize = usize::MAX >> 3;
+
+    pub fn new(permits: usize) -> Self { Self::const_new(permits) }
+
+    /// `new` for `static`s.
+    pub const fn const_new(permits: usize) -> Self {
+        assert!(permits <= Self::MAX_PERMITS, "a semaphore may not have more than MAX_PERMITS permits");
+        Self {
+            state: Mutex::new(State {
+                permits,
+                closed: false,
+                next_id: 0,
+                queue: BTreeMap::new(),
+                granted: BTreeSet::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn available_permits(&self) -> usize { self.lock().permits }
+
+    /// Add `n` permits, waking queued acquirers that now fit.
+    pub fn add_permits(&self, n: usize) {
+        let mut st = self.lock();
+        st.permits += n;
+        assert!(st.permits <= Self::MAX_PERMITS, "a semaphore may not have more than MAX_PERMITS permits");
+        st.dispatch();
+    }
+
+    /// Fail every pending and future `acquire`; held permits stay valid.
+    pub fn close(&self) {
+        let mut st = self.lock();
+        st.closed = true;
+        core::mem::take(&mut st.queue).into_values().for_each(|w| w.waker.wake());
+    }
+
+    pub fn is_closed(&self) -> bool { self.lock().closed }
+
+    pub async fn acquire(&self) -> Result<SemaphorePermit<'_>, AcquireError> {
+        self.acquire_many(1).await
+    }
+
+    pub async fn acquire_many(&self, n: u32) -> Result<SemaphorePermit<'_>, AcquireError> {
+        Acquire::new(self, n as usize).await?;
+        Ok(SemaphorePermit { sem: self, permits: n })
+    }
+
+    pub fn try_acquire(&self) -> Result<SemaphorePermit<'_>, TryAcquireError> {
+        self.try_acquire_many(1)
+    }
+
+    pub fn try_acquire_many(&self, n: u32) -> Result<SemaphorePermit<'_>, TryAcquireError> {
+        self.try_take(n as usize)?;
+        Ok(SemaphorePermit { sem: self, permits: n })
+    }
+
+    pub async fn acquire_owned(self: Arc<Self>) -> Result<OwnedSemaphorePermit, AcquireError> {
+        self.acquire_many_owned(1).await
+    }
+
+    pub async fn acquire_many_owned(self: Arc<Self>, n: u32)
+                                    -> Result<OwnedSemaphorePermit, AcquireError> {
+        Acquire::new(&self, n as usize).await?;
+        Ok(OwnedSemaphorePermit { sem: self, permits: n })
+    }
+
+    pub fn try_acquire_owned(self: Arc<Self>) -> Result<OwnedSemaphorePermit, TryAcquireError> {
+        self.try_acquire_many_owned(1)
+    }
+
+    pub fn try_acquire_many_owned(self: Arc<Self>, n: u32)
+                                  -> Result<OwnedSemaphorePermit, TryAcquireError> {
+        self.try_take(n as usize)?;
+        Ok(OwnedSemaphorePermit { sem: self, permits: n })
+    }
+
+    fn try_take(&self, n: usize) -> Result<(), TryAcquireError> {
+        let mut st = self.lock();
+        if st.closed { return Err(TryAcquireError::Closed); }
+        /* queued waiters go first */
+        if !st.queue.is_empty() || st.permits < n { return Err(TryAcquireError::NoPermits); }
+        st.permits -= n;
+        Ok(())
+    }
+
+    fn release(&self, n: usize) {
+        if n == 0 { return; }
+        let mut st = self.lock();
+        st.permits += n;
+        st.dispatch();
+    }
+}
+
+impl fmt::Debug for Semaphore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semaphore").field("permits", &self.available_permits()).finish()
+    }
+}
+
+/*──────────────────────── acquire future ──────────────────────*/
+
+/// Resolves once `needed` permits were taken on the caller's behalf.
+struct Acquire<'a> {
+    sem: &'a Semaphore,
+    needed: usize,
+    id: Option<u64>,       /* queued under this id */
+    done: bool,
+}
+
+impl<'a> Acquire<'a> {
+    fn new(sem: &'a Semaphore, needed: usize) -> Self { Self { sem, needed, id: None, done: false } }
+}
+
+impl Future for Acquire<'_> {
+    type Output = Result<(), AcquireError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut st = this.sem.lock();
+        match this.id {
+            Some(id) if st.granted.remove(&id) => {
+                this.done = true;
+                return Poll::Ready(Ok(()));
+            }
+            _ if st.closed => return Poll::Ready(Err(AcquireError(()))),
+            Some(id) => {
+                if let Some(w) = st.queue.get_mut(&id) { w.waker.clone_from(cx.waker()); }
+                return Poll::Pending;
+            }
+            None => {}
+        }
+        if st.queue.is_empty() && st.permits >= this.needed {
+            st.permits -= this.needed;
+            this.done = true;
+            return Poll::Ready(Ok(()));
+        }
+        let id = st.next_id;
+        st.next_id += 1;
+        st.queue.insert(id, Waiter { needed: this.needed, waker: cx.waker().clone() });
+        this.id = Some(id);
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let (Some(id), false) = (self.id, self.done) else { return };
+        let mut st = self.sem.lock();
+        if st.granted.remove(&id) {
+            /* served but abandoned – give the permits back */
+            st.permits += self.needed;
+        } else {
+            st.queue.remove(&id);
+        }
+        st.dispatch();
+    }
+}
+
+/*──────────────────────── permits ─────────────────────────────*/
+
+/// Borrowed permit(s); released on drop.
+#[must_use]
+pub struct SemaphorePermit<'a> {
+    sem: &'a Semaphore,
+    permits: u32,
+}
+
+impl SemaphorePermit<'_> {
+    /// Keep the permits out of the semaphore for good.
+    pub fn forget(mut self) { self.permits = 0; }
+
+    pub fn num_permits(&self) -> usize { self.permits as usize }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) { self.sem.release(self.permits as usize); }
+}
+
+impl fmt::Debug for SemaphorePermit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SemaphorePermit").field("permits", &self.permits).finish()
+    }
+}
+
+/// Permit(s) that keep their semaphore alive; movable into tasks.
+#[must_use]
+pub struct OwnedSemaphorePermit {
+    sem: Arc<Semaphore>,
+    permits: u32,
+}
+
+impl OwnedSemaphorePermit {
+    pub fn forget(mut self) { self.permits = 0; }
+
+    pub fn num_permits(&self) -> usize { self.permits as usize }
+
+    pub fn semaphore(&self) -> &Arc<Semaphore> { &self.sem }
+}
+
+impl Drop for OwnedSemaphorePermit {
+    fn drop(&mut self) { self.sem.release(self.permits as usize); }
+}
+
+impl fmt::Debug for OwnedSemaphorePermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedSemaphorePermit").field("permits", &self.permits).finish()
+    }
+}
@@ -2,9 +2,13 @@
 //
 //! Works on all three runtime flavours (native / browser / WASI).
 //!
-//! * `spawn(…)`, `channel(…)`                → use crate-level [`Rt`].
-//! * `spawn_with::<MyRt>(…)`, `channel_with::<MyRt, _>(…)`
-//!                                             → pick any `R: Runtime`.
+//! * `spawn(…)`, `channel(…)`, `channel_bounded(cap)` → use crate-level [`Rt`].
+//! * `spawn_local(…)` for `!Send` futures, `spawn_blocking(…)` for blocking work.
+//! * `spawn_with::<MyRt>(…)`, `channel_with::<MyRt, _>(…)` → pick any `R: Runtime`.
+//! * [`task_local!`](crate::task_local) keys and [`add_spawn_hook`] carry
+//!   context into spawned tasks.
+//! * [`TaskBuilder`] names a task; [`spawn_catching`] and builder‑spawned
+//!   tasks report panics to the [`set_panic_handler`] hook.
 
 use crate::{api::Runtime, Rt};
 
@@ -12,38 +16,129 @@ use crate::{api::Runtime, Rt};
 
 // ─ default runtime ─
 #[cfg(not(target_arch = "wasm32"))]           // multithread
-pub fn spawn<F>(fut: F) -> <Rt as Runtime>::JoinHandle
+pub fn spawn<F>(fut: F) -> JoinHandle<F::Output>
 where
-    F: core::future::Future<Output = ()> + Send + 'static,
+    F: core::future::Future + Send + 'static,
+    F::Output: Send + 'static,
 {
     Rt::spawn(fut)
 }
 #[cfg(target_arch = "wasm32")]                // single-thread
-pub fn spawn<F>(fut: F) -> <Rt as Runtime>::JoinHandle
+pub fn spawn<F>(fut: F) -> JoinHandle<F::Output>
 where
-    F: core::future::Future<Output = ()> + 'static,
+    F: core::future::Future + 'static,
+    F::Output: 'static,
 {
     Rt::spawn(fut)
 }
 
 // ─ generic runtime ─
 #[cfg(not(target_arch = "wasm32"))]
-pub fn spawn_with<R, F>(fut: F) -> <R as Runtime>::JoinHandle
+pub fn spawn_with<R, F>(fut: F) -> JoinHandle<F::Output>
 where
     R: Runtime,
-    F: core::future::Future<Output = ()> + Send + 'static,
+    F: core::future::Future + Send + 'static,
+    F::Output: Send + 'static,
 {
     R::spawn(fut)
 }
 #[cfg(target_arch = "wasm32")]
-pub fn spawn_with<R, F>(fut: F) -> <R as Runtime>::JoinHandle
+pub fn spawn_with<R, F>(fut: F) -> JoinHandle<F::Output>
 where
     R: Runtime,
-    F: core::future::Future<Output = ()> + 'static,
+    F: core::future::Future + 'static,
+    F::Output: 'static,
 {
     R::spawn(fut)
 }
 
+/// [`spawn`] that reports a panic to the [panic handler](set_panic_handler);
+/// short for `TaskBuilder::new().spawn(fut)`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_catching<F>(fut: F) -> JoinHandle<F::Output>
+where
+    F: core::future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    TaskBuilder::new().spawn(fut)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn spawn_catching<F>(fut: F) -> JoinHandle<F::Output>
+where
+    F: core::future::Future + 'static,
+    F::Output: 'static,
+{
+    TaskBuilder::new().spawn(fut)
+}
+
+/*──────────────────────── spawn_local ─────────────────────────*/
+
+/// Spawn a `!Send` future on this thread.
+///
+/// Native needs a local context – wrap the caller in [`run_local`];
+/// browser / WASI are single‑threaded and accept it anywhere.
+pub fn spawn_local<F>(fut: F) -> JoinHandle<F::Output>
+where
+    F: core::future::Future + 'static,
+    F::Output: 'static,
+{
+    Rt::spawn_local(fut)
+}
+pub fn spawn_local_with<R, F>(fut: F) -> JoinHandle<F::Output>
+where
+    R: Runtime,
+    F: core::future::Future + 'static,
+    F::Output: 'static,
+{
+    R::spawn_local(fut)
+}
+
+/// Drive `fut` with [`spawn_local`] enabled (a Tokio `LocalSet` on native).
+pub async fn run_local<F: core::future::Future>(fut: F) -> F::Output { Rt::run_local(fut).await }
+pub async fn run_local_with<R: Runtime, F: core::future::Future>(fut: F) -> F::Output {
+    R::run_local(fut).await
+}
+
+/*──────────────────────── spawn_blocking ──────────────────────*/
+
+/// Run blocking `f` on the backend's blocking pool; wasm runs it inline
+/// as a task, so keep it short there.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Rt::spawn_blocking(f)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn spawn_blocking<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + 'static,
+    T: 'static,
+{
+    Rt::spawn_blocking(f)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_blocking_with<R, F, T>(f: F) -> JoinHandle<T>
+where
+    R: Runtime,
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    R::spawn_blocking(f)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn spawn_blocking_with<R, F, T>(f: F) -> JoinHandle<T>
+where
+    R: Runtime,
+    F: FnOnce() -> T + 'static,
+    T: 'static,
+{
+    R::spawn_blocking(f)
+}
+
 /*──────────────────────── channels ───────────────────────────*/
 
 // default runtime
@@ -92,6 +187,190 @@ where
     R::channel()
 }
 
+/*──────────────────────── bounded channels ───────────────────*/
+
+// default runtime
+#[cfg(not(target_arch = "wasm32"))]
+pub fn channel_bounded<T>(cap: usize) -> (
+    <Rt as Runtime>::BoundedSender<T>,
+    <Rt as Runtime>::BoundedReceiver<T>,
+)
+where
+    T: Send + 'static,
+{
+    Rt::channel_bounded(cap)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn channel_bounded<T>(cap: usize) -> (
+    <Rt as Runtime>::BoundedSender<T>,
+    <Rt as Runtime>::BoundedReceiver<T>,
+)
+where
+    T: 'static,
+{
+    Rt::channel_bounded(cap)
+}
+
+// generic runtime
+#[cfg(not(target_arch = "wasm32"))]
+pub fn channel_bounded_with<R, T>(cap: usize) -> (
+    <R as Runtime>::BoundedSender<T>,
+    <R as Runtime>::BoundedReceiver<T>,
+)
+where
+    R: Runtime,
+    T: Send + 'static,
+{
+    R::channel_bounded(cap)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn channel_bounded_with<R, T>(cap: usize) -> (
+    <R as Runtime>::BoundedSender<T>,
+    <R as Runtime>::BoundedReceiver<T>,
+)
+where
+    R: Runtime,
+    T: 'static,
+{
+    R::channel_bounded(cap)
+}
+
+/*──────────────────────── oneshot / broadcast / watch ────────*/
+
+// default runtime
+#[cfg(not(target_arch = "wasm32"))]
+pub fn oneshot<T>() -> (
+    <Rt as Runtime>::OneshotSender<T>,
+    <Rt as Runtime>::OneshotReceiver<T>,
+)
+where
+    T: Send + 'static,
+{
+    Rt::oneshot()
+}
+#[cfg(target_arch = "wasm32")]
+pub fn oneshot<T>() -> (
+    <Rt as Runtime>::OneshotSender<T>,
+    <Rt as Runtime>::OneshotReceiver<T>,
+)
+where
+    T: 'static,
+{
+    Rt::oneshot()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn broadcast<T>(cap: usize) -> (
+    <Rt as Runtime>::BroadcastSender<T>,
+    <Rt as Runtime>::BroadcastReceiver<T>,
+)
+where
+    T: Clone + Send + 'static,
+{
+    Rt::broadcast(cap)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn broadcast<T>(cap: usize) -> (
+    <Rt as Runtime>::BroadcastSender<T>,
+    <Rt as Runtime>::BroadcastReceiver<T>,
+)
+where
+    T: Clone + 'static,
+{
+    Rt::broadcast(cap)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch<T>(init: T) -> (
+    <Rt as Runtime>::WatchSender<T>,
+    <Rt as Runtime>::WatchReceiver<T>,
+)
+where
+    T: Send + 'static,
+{
+    Rt::watch(init)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn watch<T>(init: T) -> (
+    <Rt as Runtime>::WatchSender<T>,
+    <Rt as Runtime>::WatchReceiver<T>,
+)
+where
+    T: 'static,
+{
+    Rt::watch(init)
+}
+
+// generic runtime
+#[cfg(not(target_arch = "wasm32"))]
+pub fn oneshot_with<R, T>() -> (
+    <R as Runtime>::OneshotSender<T>,
+    <R as Runtime>::OneshotReceiver<T>,
+)
+where
+    R: Runtime,
+    T: Send + 'static,
+{
+    R::oneshot()
+}
+#[cfg(target_arch = "wasm32")]
+pub fn oneshot_with<R, T>() -> (
+    <R as Runtime>::OneshotSender<T>,
+    <R as Runtime>::OneshotReceiver<T>,
+)
+where
+    R: Runtime,
+    T: 'static,
+{
+    R::oneshot()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn broadcast_with<R, T>(cap: usize) -> (
+    <R as Runtime>::BroadcastSender<T>,
+    <R as Runtime>::BroadcastReceiver<T>,
+)
+where
+    R: Runtime,
+    T: Clone + Send + 'static,
+{
+    R::broadcast(cap)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn broadcast_with<R, T>(cap: usize) -> (
+    <R as Runtime>::BroadcastSender<T>,
+    <R as Runtime>::BroadcastReceiver<T>,
+)
+where
+    R: Runtime,
+    T: Clone + 'static,
+{
+    R::broadcast(cap)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn watch_with<R, T>(init: T) -> (
+    <R as Runtime>::WatchSender<T>,
+    <R as Runtime>::WatchReceiver<T>,
+)
+where
+    R: Runtime,
+    T: Send + 'static,
+{
+    R::watch(init)
+}
+#[cfg(target_arch = "wasm32")]
+pub fn watch_with<R, T>(init: T) -> (
+    <R as Runtime>::WatchSender<T>,
+    <R as Runtime>::WatchReceiver<T>,
+)
+where
+    R: Runtime,
+    T: 'static,
+{
+    R::watch(init)
+}
+
 /*──────────────────────── yield helpers ─────────────────────*/
 
 pub async fn yield_now() { Rt::yield_now().await }
@@ -101,3 +380,16 @@ pub async fn yield_now_with<R: Runtime>() { R::yield_now().await }
 /*──────────────────────── convenience re-export ─────────────*/
 
 pub use crate::SenderExt;   // lets users call `tx.send_owned(v)`
+pub use crate::handle::{JoinError, JoinHandle};
+pub use crate::builder::TaskBuilder;
+pub use crate::hook::{add_spawn_hook, SpawnContext};
+pub use crate::local::{AccessError, LocalKey, TaskLocalFuture};
+pub use crate::group::{JoinSet, TaskGroup};
+pub use crate::panic::{set_panic_handler, PanicReport};
+pub use crate::api::{
+    BoundedSendMessage, ReceiverExt,
+    OneshotSend, BroadcastSend, BroadcastRecv, WatchSend, WatchRecv,
+};
+pub use crate::error::{
+    BroadcastRecvError, Closed, RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError,
+};
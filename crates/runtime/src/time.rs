@@ -1,21 +1,47 @@
-use crate::{api::Runtime, Rt};
-use core::time::Duration;
-use instant::Instant;
+use crate::{api::Runtime, metrics, Rt};
+use core::{future::IntoFuture, time::Duration};
+
+pub use crate::clock::Instant;
+pub use crate::interval::{Interval, MissedTickBehavior};
+pub use crate::timeout::{Elapsed, FutureExt, Timeout};
 
 /*──────────── sleep ───────────*/
 
-pub async fn sleep(d: Duration) { Rt::sleep(d).await }
-pub async fn sleep_with<R: Runtime>(d: Duration) { R::sleep(d).await }
+pub async fn sleep(d: Duration) { metrics::sleep::<Rt>(d).await }
+pub async fn sleep_with<R: Runtime>(d: Duration) { metrics::sleep::<R>(d).await }
 
-/*──────────── now() ───────────*/
+/// Sleep until `deadline`; returns at once if it already passed.
+pub async fn sleep_until(deadline: Instant) { sleep_until_with::<Rt>(deadline).await }
+pub async fn sleep_until_with<R: Runtime>(deadline: Instant) {
+    /* loop: browser timers have whole‑millisecond resolution */
+    loop {
+        let now = R::now();
+        if now >= deadline { break; }
+        metrics::sleep::<R>(deadline - now).await;
+    }
+}
 
-pub fn now_ms() -> u128 {
-    Instant::now().elapsed().as_millis()
+/*──────────── interval ───────────*/
+
+/// Ticks every `period`; the first tick completes immediately.
+pub fn interval(period: Duration) -> Interval { interval_with::<Rt>(period) }
+pub fn interval_with<R: Runtime>(period: Duration) -> Interval<R> {
+    Interval::new(R::now(), period)
 }
-pub fn now() -> Duration {
-    Instant::now().elapsed()
+
+/// Ticks at `start`, `start + period`, … – for schedules aligned to a
+/// known instant.
+pub fn interval_at(start: Instant, period: Duration) -> Interval { Interval::new(start, period) }
+pub fn interval_at_with<R: Runtime>(start: Instant, period: Duration) -> Interval<R> {
+    Interval::new(start, period)
 }
 
+/*──────────── now() ───────────*/
+
+/// Current reading of the runtime's monotonic clock.
+pub fn now() -> Instant { Rt::now() }
+pub fn now_with<R: Runtime>() -> Instant { R::now() }
+
 /// Milliseconds since the Unix epoch **across all runtimes**.
 ///
 /// * Native / WASI → `SystemTime`.
@@ -38,7 +64,7 @@ pub fn epoch_ms() -> u128 {
             .as_millis()
     }
 
-    #[cfg(all(not(target_arch = "wasm32")))]
+    #[cfg(not(target_arch = "wasm32"))]
     {
         use std::time::{SystemTime, UNIX_EPOCH};
         SystemTime::now()
@@ -49,37 +75,21 @@ pub fn epoch_ms() -> u128 {
 }
 
 
-/*──────────── timeout (default runtime) ───────────*/
-
-// native needs `Send`
-#[cfg(not(target_arch = "wasm32"))]
-pub async fn timeout<F, T, E>(d: Duration, fut: F)
-                              -> Result<Result<T, E>, ()>
-where
-    F: core::future::Future<Output=Result<T, E>> + Send + 'static,
-{ Rt::timeout(d, fut).await }
-
-#[cfg(target_arch = "wasm32")]
-pub async fn timeout<F, T, E>(d: Duration, fut: F)
-                              -> Result<Result<T, E>, ()>
-where
-    F: core::future::Future<Output=Result<T, E>> + 'static,
-{ Rt::timeout(d, fut).await }
-
-/*──────────── timeout_with (generic runtime) ───────────*/
-
-#[cfg(not(target_arch = "wasm32"))]
-pub async fn timeout_with<R, F, T, E>(d: Duration, fut: F)
-                                      -> Result<Result<T, E>, ()>
-where
-    R: Runtime,
-    F: core::future::Future<Output=Result<T, E>> + Send + 'static,
-{ R::timeout(d, fut).await }
-
-#[cfg(target_arch = "wasm32")]
-pub async fn timeout_with<R, F, T, E>(d: Duration, fut: F)
-                                      -> Result<Result<T, E>, ()>
-where
-    R: Runtime,
-    F: core::future::Future<Output=Result<T, E>> + 'static,
-{ R::timeout(d, fut).await }
+/*──────────── timeout ───────────*/
+
+/// Give `fut` at most `d` to finish.
+pub fn timeout<F: IntoFuture>(d: Duration, fut: F) -> Timeout<F::IntoFuture> {
+    timeout_with::<Rt, F>(d, fut)
+}
+pub fn timeout_with<R: Runtime, F: IntoFuture>(d: Duration, fut: F) -> Timeout<F::IntoFuture, R> {
+    Timeout::new(fut.into_future(), R::now().checked_add(d))
+}
+
+/// Give `fut` until `deadline` to finish.
+pub fn timeout_at<F: IntoFuture>(deadline: Instant, fut: F) -> Timeout<F::IntoFuture> {
+    timeout_at_with::<Rt, F>(deadline, fut)
+}
+pub fn timeout_at_with<R: Runtime, F: IntoFuture>(deadline: Instant, fut: F)
+                                                  -> Timeout<F::IntoFuture, R> {
+    Timeout::new(fut.into_future(), Some(deadline))
+}
@@ -0,0 +1,98 @@
+//! Deadline wrapper for any future.
+//!
+//! * works for every output type – no `Result` shape required;
+//! * the wrapped future may borrow (no `'static`, no `Send`);
+//! * the deadline is fixed when the [`Timeout`] is created, not when it
+//!   is first polled.
+
+use crate::{api::Runtime, clock::Instant, metrics, Rt};
+use core::{
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+/*──────────────────────── error ──────────────────────────────*/
+
+/// The deadline passed before the inner future completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+/*──────────────────────── future ─────────────────────────────*/
+
+/// Future returned by [`time::timeout`](crate::time::timeout) and
+/// friends; yields `Err(Elapsed)` once the deadline passes.
+#[must_use = "futures do nothing unless polled"]
+pub struct Timeout<F, R: Runtime = Rt> {
+    fut: F,
+    deadline: Option<Instant>,     /* `None` → too far away to represent */
+    sleep: Option<Pin<Box<metrics::Sleep<R>>>>,
+    _rt: PhantomData<fn() -> R>,
+}
+
+impl<F, R: Runtime> Timeout<F, R> {
+    pub(crate) fn new(fut: F, deadline: Option<Instant>) -> Self {
+        Self { fut, deadline, sleep: None, _rt: PhantomData }
+    }
+
+    /// When the timeout fires, if ever.
+    pub fn deadline(&self) -> Option<Instant> { self.deadline }
+
+    pub fn get_ref(&self) -> &F { &self.fut }
+
+    pub fn get_mut(&mut self) -> &mut F { &mut self.fut }
+
+    pub fn into_inner(self) -> F { self.fut }
+}
+
+impl<F: Future, R: Runtime> Future for Timeout<F, R> {
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `fut` is structurally pinned and never moved out while
+        // pinned; the other fields are `Unpin`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let fut = unsafe { Pin::new_unchecked(&mut this.fut) };
+        if let Poll::Ready(v) = fut.poll(cx) { return Poll::Ready(Ok(v)); }
+
+        let Some(deadline) = this.deadline else { return Poll::Pending };
+        loop {
+            let now = R::now();
+            if now >= deadline { return Poll::Ready(Err(Elapsed(()))); }
+            let sleep = this.sleep.get_or_insert_with(|| Box::pin(metrics::sleep::<R>(deadline - now)));
+            if sleep.as_mut().poll(cx).is_pending() { return Poll::Pending; }
+            /* re‑check: some timers fire a little early */
+            this.sleep = None;
+        }
+    }
+}
+
+impl<F, R: Runtime> fmt::Debug for Timeout<F, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout").field("deadline", &self.deadline).finish_non_exhaustive()
+    }
+}
+
+/*──────────────────────── extension ──────────────────────────*/
+
+/// `.timeout(d)` / `.timeout_at(t)` on every future.
+pub trait FutureExt: Future + Sized {
+    fn timeout(self, d: Duration) -> Timeout<Self> { crate::time::timeout(d, self) }
+
+    fn timeout_at(self, deadline: Instant) -> Timeout<Self> {
+        crate::time::timeout_at(deadline, self)
+    }
+}
+
+impl<F: Future> FutureExt for F {}
@@ -0,0 +1,31 @@
+//! `block_on` drives a future from plain synchronous code.
+#![cfg(not(target_arch = "wasm32"))]
+
+use everywhere_runtime::{block_on, task, time};
+use std::{cell::Cell, rc::Rc, time::Duration};
+
+#[test]
+fn block_on_returns_output() {
+    let v = block_on(async {
+        time::sleep(Duration::from_millis(5)).await;
+        40 + 2
+    });
+    assert_eq!(v, 42);
+}
+
+/// Local tasks work inside `block_on` without an explicit `run_local`.
+#[test]
+fn block_on_allows_spawn_local() {
+    let hits = Rc::new(Cell::new(0));
+    let h = hits.clone();
+    block_on(async move {
+        task::spawn_local(async move { h.set(h.get() + 1) }).await.unwrap();
+    });
+    assert_eq!(hits.get(), 1);
+}
+
+/// Two sequential calls each get their own runtime.
+#[test]
+fn block_on_is_reentrant_sequentially() {
+    assert_eq!(block_on(async { 1 }) + block_on(async { 2 }), 3);
+}
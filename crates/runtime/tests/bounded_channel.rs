@@ -0,0 +1,43 @@
+use core::{sync::atomic::{AtomicU32, Ordering}, time::Duration};
+use everywhere_runtime::{prelude::*, task, time, SendError};
+use everywhere_test::cross_test;
+use std::sync::Arc;
+
+/// `try_send` tells “full” apart from “closed”.
+#[cross_test]
+async fn bounded_try_send_full_then_closed() {
+    let (mut tx, mut rx) = task::channel_bounded::<u32>(2);
+
+    assert!(tx.try_send(1).is_ok());
+    assert!(tx.try_send(2).is_ok());
+    let err = tx.try_send(3).unwrap_err();
+    assert!(err.is_full());
+    assert_eq!(err.into_inner(), 3);
+
+    assert_eq!(rx.recv().await, Some(1));
+    assert!(tx.try_send(3).is_ok());
+
+    drop(rx);
+    assert!(tx.try_send(4).unwrap_err().is_closed());
+    assert_eq!(tx.send(5).await, Err(SendError(5)));
+}
+
+/// `send().await` parks the producer until the consumer makes room.
+#[cross_test]
+async fn bounded_send_waits_for_space() {
+    let (mut tx, mut rx) = task::channel_bounded::<u32>(1);
+    let sent = Arc::new(AtomicU32::new(0));
+    let sent2 = sent.clone();
+
+    task::spawn(async move {
+        for i in 0..3 {
+            tx.send(i).await.unwrap();
+            sent2.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(sent.load(Ordering::SeqCst), 1);    // second send is parked
+
+    for i in 0..3 { assert_eq!(rx.recv().await, Some(i)); }
+}
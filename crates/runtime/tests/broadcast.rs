@@ -0,0 +1,36 @@
+use everywhere_runtime::{prelude::*, task, BroadcastRecvError, SendError};
+use everywhere_test::cross_test;
+
+/// Every subscriber gets every value, then `Closed` once senders are gone.
+#[cross_test]
+async fn broadcast_fans_out() {
+    let (tx, mut a) = task::broadcast::<u32>(4);
+    let mut b = tx.subscribe();
+
+    assert_eq!(tx.send(1), Ok(2));
+    assert_eq!(a.recv().await, Ok(1));
+    assert_eq!(b.recv().await, Ok(1));
+
+    drop(tx);
+    assert_eq!(a.recv().await, Err(BroadcastRecvError::Closed));
+}
+
+/// A slow receiver is told how much it missed, then resumes at the oldest.
+#[cross_test]
+async fn broadcast_reports_lag() {
+    let (tx, mut rx) = task::broadcast::<u32>(2);
+    for i in 0..5 { tx.send(i).unwrap(); }
+
+    assert_eq!(rx.recv().await, Err(BroadcastRecvError::Lagged(3)));
+    assert_eq!(rx.recv().await, Ok(3));
+    assert_eq!(rx.recv().await, Ok(4));
+}
+
+/// Sending without receivers fails and hands the value back.
+#[cross_test]
+async fn broadcast_without_receivers() {
+    let (tx, rx) = task::broadcast::<u32>(2);
+    drop(rx);
+    assert_eq!(tx.receiver_count(), 0);
+    assert_eq!(tx.send(9), Err(SendError(9)));
+}
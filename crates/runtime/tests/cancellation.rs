@@ -0,0 +1,68 @@
+use core::{sync::atomic::{AtomicBool, Ordering}, time::Duration};
+use everywhere_runtime::{sync::CancellationToken, task, time};
+use everywhere_test::cross_test;
+use std::sync::Arc;
+
+/// Parents cancel their children, never the other way round.
+#[cross_test]
+async fn cancel_propagates_down_only() {
+    let root = CancellationToken::new();
+    let child = root.child_token();
+    let grandchild = child.child_token();
+
+    child.cancel();
+    assert!(grandchild.is_cancelled());
+    assert!(!root.is_cancelled());
+
+    let sibling = root.child_token();
+    root.cancel();
+    assert!(sibling.is_cancelled());
+    assert!(root.child_token().is_cancelled());
+}
+
+/// A task parked on `cancelled()` wakes up when the token fires.
+#[cross_test]
+async fn cancelled_wakes_task() {
+    let token = CancellationToken::new();
+    let stopped = Arc::new(AtomicBool::new(false));
+
+    let (t, s) = (token.clone(), stopped.clone());
+    let h = task::spawn(async move {
+        t.cancelled().await;
+        s.store(true, Ordering::SeqCst);
+    });
+
+    time::sleep(Duration::from_millis(10)).await;
+    assert!(!stopped.load(Ordering::SeqCst));
+    token.cancel();
+    h.await.unwrap();
+    assert!(stopped.load(Ordering::SeqCst));
+}
+
+/// `run_until_cancelled` returns the output, or `None` once cancelled.
+#[cross_test]
+async fn run_until_cancelled_races() {
+    let token = CancellationToken::new();
+    assert_eq!(token.run_until_cancelled(async { 7 }).await, Some(7));
+
+    let t = token.clone();
+    task::spawn(async move {
+        time::sleep(Duration::from_millis(5)).await;
+        t.cancel();
+    });
+    let slow = time::sleep(Duration::from_secs(10));
+    assert_eq!(token.run_until_cancelled(slow).await, None);
+}
+
+/// Guards cancel on drop unless disarmed.
+#[cross_test]
+async fn drop_guard_cancels() {
+    let token = CancellationToken::new();
+    drop(token.clone().drop_guard());
+    assert!(token.is_cancelled());
+
+    let token = CancellationToken::new();
+    let kept = token.clone().drop_guard().disarm();
+    drop(kept);
+    assert!(!token.is_cancelled());
+}
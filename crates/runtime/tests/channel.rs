@@ -1,7 +1,6 @@
 use core::time::Duration;
 use everywhere_runtime::{task, time, prelude::*};
 use everywhere_test::cross_test;
-use futures_util::StreamExt;
 
 /// Simple round‑trip channel test.
 #[cross_test]
@@ -0,0 +1,72 @@
+use core::time::Duration;
+use everywhere_runtime::{prelude::*, task, Closed, RecvTimeoutError, TryRecvError};
+use everywhere_test::cross_test;
+use futures_util::{stream, SinkExt, StreamExt};
+
+/// Receivers are streams: combinators work the same on every backend.
+#[cross_test]
+async fn receiver_is_a_stream() {
+    let (tx, rx) = task::channel::<u32>();
+    for i in 1..=4 { tx.send_owned(i).unwrap(); }
+    drop(tx);
+
+    let doubled: Vec<u32> = rx.map(|v| v * 2).collect().await;
+    assert_eq!(doubled, [2, 4, 6, 8]);
+}
+
+/// A stream can be piped into a sender with `forward`.
+#[cross_test]
+async fn sender_is_a_sink() {
+    let (tx, rx) = task::channel::<u32>();
+    stream::iter([1, 2, 3]).map(Ok).forward(tx).await.unwrap();
+
+    assert_eq!(rx.collect::<Vec<_>>().await, [1, 2, 3]);
+}
+
+/// Sinking into a channel whose receiver is gone fails with `Closed`.
+#[cross_test]
+async fn sink_reports_closed() {
+    let (mut tx, rx) = task::channel::<u32>();
+    drop(rx);
+    assert_eq!(tx.send(1).await, Err(Closed));
+}
+
+/// Bounded receivers stream too.
+#[cross_test]
+async fn bounded_receiver_is_a_stream() {
+    let (mut tx, mut rx) = task::channel_bounded::<u32>(2);
+    task::spawn(async move {
+        for i in 0..5 { tx.send(i).await.unwrap(); }
+    });
+
+    assert_eq!((&mut rx).take(5).collect::<Vec<_>>().await, [0, 1, 2, 3, 4]);
+    assert_eq!(rx.next().await, None);
+}
+
+/// `try_recv` tells “empty” apart from “closed”.
+#[cross_test]
+async fn try_recv_empty_then_closed() {
+    let (tx, mut rx) = task::channel::<u32>();
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+
+    tx.send_owned(7).unwrap();
+    assert_eq!(rx.try_recv(), Ok(7));
+
+    drop(tx);
+    assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+}
+
+/// `recv_timeout` returns a value, times out, or reports closure.
+#[cross_test]
+async fn recv_timeout_outcomes() {
+    let (mut tx, mut rx) = task::channel_bounded::<u32>(1);
+    let short = Duration::from_millis(20);
+
+    assert_eq!(rx.recv_timeout(short).await, Err(RecvTimeoutError::Timeout));
+
+    tx.try_send(1).unwrap();
+    assert_eq!(rx.recv_timeout(short).await, Ok(1));
+
+    drop(tx);
+    assert_eq!(rx.recv_timeout(short).await, Err(RecvTimeoutError::Closed));
+}
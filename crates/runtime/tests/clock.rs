@@ -0,0 +1,45 @@
+use core::time::Duration;
+use everywhere_runtime::time::{self, Instant};
+use everywhere_test::cross_test;
+
+const MS: Duration = Duration::from_millis(1);
+
+/// The clock actually moves, and arithmetic saturates like `std`.
+#[cross_test]
+async fn instant_measures_sleep() {
+    let start = Instant::now();
+    time::sleep(20 * MS).await;
+
+    assert!(start.elapsed() >= 15 * MS);
+    assert!(time::now() > start);
+
+    let later = start.checked_add(5 * MS).unwrap();
+    assert_eq!(later.duration_since(start), 5 * MS);
+    assert_eq!(start.duration_since(later), Duration::ZERO);
+    assert_eq!(later - start, 5 * MS);
+}
+
+/// `sleep_until` waits for the deadline; a past deadline returns at once.
+#[cross_test]
+async fn sleep_until_deadline() {
+    let deadline = time::now() + 20 * MS;
+    time::sleep_until(deadline).await;
+    assert!(time::now() >= deadline);
+
+    let before = time::now();
+    time::sleep_until(before - MS).await;
+    assert!(before.elapsed() < 15 * MS);
+}
+
+/// `timeout_at` measures against an absolute deadline.
+#[cross_test]
+async fn timeout_at_deadline() {
+    let deadline = time::now() + 10 * MS;
+    let res = time::timeout_at(deadline, async {
+        time::sleep(100 * MS).await;
+        Ok::<_, ()>(())
+    })
+        .await;
+    assert!(res.is_err());
+    assert!(time::now() >= deadline);
+}
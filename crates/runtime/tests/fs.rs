@@ -0,0 +1,93 @@
+// The browser build has no `fs` module.
+#![cfg(not(all(feature = "browser", target_arch = "wasm32")))]
+
+use everywhere_runtime::fs::{self, AsyncReadExt, AsyncWriteExt, File};
+use everywhere_test::cross_test;
+use std::{
+    io::ErrorKind,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+/// Fresh, empty scratch directory per test; removed again on drop.
+struct Scratch(PathBuf);
+
+impl Deref for Scratch {
+    type Target = Path;
+    fn deref(&self) -> &Path { &self.0 }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) { let _ = std::fs::remove_dir_all(&self.0); }
+}
+
+async fn scratch(test: &str) -> Scratch {
+    let dir = std::env::temp_dir().join(format!("everywhere-fs-{}-{test}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).await.unwrap();
+    Scratch(dir)
+}
+
+/// Whole‑file helpers round‑trip bytes and text.
+#[cross_test]
+async fn write_then_read() {
+    let dir = scratch("rw").await;
+    let file = dir.join("note.txt");
+
+    fs::write(&file, b"hello").await.unwrap();
+    assert_eq!(fs::read(&file).await.unwrap(), b"hello");
+    assert_eq!(fs::read_to_string(&file).await.unwrap(), "hello");
+    assert_eq!(fs::metadata(&file).await.unwrap().len(), 5);
+
+    fs::remove_file(&file).await.unwrap();
+    assert_eq!(fs::read(&file).await.unwrap_err().kind(), ErrorKind::NotFound);
+}
+
+/// `create_dir_all` builds nested paths; `read_dir` lists them.
+#[cross_test]
+async fn nested_dirs_and_listing() {
+    let dir = scratch("dirs").await;
+    let nested = dir.join("a/b/c");
+    fs::create_dir_all(&nested).await.unwrap();
+    fs::write(nested.join("one"), "1").await.unwrap();
+    fs::write(nested.join("two"), "22").await.unwrap();
+
+    let mut rd = fs::read_dir(&nested).await.unwrap();
+    let mut names = Vec::new();
+    while let Some(entry) = rd.next_entry().await.unwrap() {
+        assert_eq!(entry.path(), nested.join(entry.file_name()));
+        names.push((entry.file_name().into_string().unwrap(), entry.metadata().await.unwrap().len()));
+    }
+    names.sort();
+    assert_eq!(names, [("one".to_owned(), 1), ("two".to_owned(), 2)]);
+}
+
+/// `File` streams in both directions.
+#[cross_test]
+async fn file_streams() {
+    let dir = scratch("stream").await;
+    let path = dir.join("chunks.bin");
+
+    let mut out = File::create(&path).await.unwrap();
+    for chunk in 0u8..4 {
+        out.write_all(&[chunk; 1024]).await.unwrap();
+    }
+    out.close().await.unwrap();
+
+    let mut input = File::open(&path).await.unwrap();
+    let mut first = [0u8; 1024];
+    input.read_exact(&mut first).await.unwrap();
+    assert!(first.iter().all(|&b| b == 0));
+
+    let mut rest = Vec::new();
+    input.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest.len(), 3 * 1024);
+    assert_eq!(rest[2 * 1024], 3);
+}
+
+/// Missing files fail with the usual error kinds.
+#[cross_test]
+async fn open_missing_file() {
+    let dir = scratch("missing").await;
+    assert_eq!(File::open(dir.join("nope")).await.unwrap_err().kind(), ErrorKind::NotFound);
+}
@@ -0,0 +1,71 @@
+use core::time::Duration;
+use everywhere_runtime::{task, time::{self, MissedTickBehavior}};
+use everywhere_test::cross_test;
+use futures_util::StreamExt;
+
+const P: Duration = Duration::from_millis(20);
+
+/// Ticks land on `start + n·period`; the first one is immediate.
+#[cross_test]
+async fn ticks_follow_schedule() {
+    let mut iv = time::interval(P);
+    let first = iv.tick().await;
+    assert!(first.elapsed() < P);
+
+    let ticks: Vec<_> = iv.by_ref().take(3).collect().await;
+    assert_eq!(ticks, [first + P, first + 2 * P, first + 3 * P]);
+    assert!(time::now() >= first + 3 * P);
+}
+
+/// `interval_at` waits for its start instant.
+#[cross_test]
+async fn interval_at_waits_for_start() {
+    let start = time::now() + P;
+    let mut iv = time::interval_at(start, P);
+    assert_eq!(iv.tick().await, start);
+    assert!(time::now() >= start);
+}
+
+/// Stalls for 3½ periods and checks how each policy catches up.
+async fn after_stall(behavior: MissedTickBehavior) -> (time::Instant, Vec<time::Instant>) {
+    let mut iv = time::interval(P);
+    iv.set_missed_tick_behavior(behavior);
+    let start = iv.tick().await;
+    time::sleep(P * 7 / 2).await;
+
+    let mut ticks = Vec::new();
+    for _ in 0..2 { ticks.push(iv.tick().await); }
+    (start, ticks)
+}
+
+#[cross_test]
+async fn burst_catches_up_back_to_back() {
+    let (start, ticks) = after_stall(MissedTickBehavior::Burst).await;
+    assert_eq!(ticks, [start + P, start + 2 * P]);
+}
+
+#[cross_test]
+async fn skip_realigns_to_schedule() {
+    let (start, ticks) = after_stall(MissedTickBehavior::Skip).await;
+    assert_eq!(ticks, [start + P, start + 4 * P]);
+}
+
+#[cross_test]
+async fn delay_restarts_after_late_tick() {
+    let (start, ticks) = after_stall(MissedTickBehavior::Delay).await;
+    assert_eq!(ticks[0], start + P);
+    assert!(ticks[1] >= start + P * 9 / 2);
+}
+
+/// An interval can move into a spawned task.
+#[cross_test]
+async fn interval_in_spawned_task() {
+    let n = task::spawn(async {
+        let mut iv = time::interval(Duration::from_millis(5));
+        for _ in 0..3 { iv.tick().await; }
+        3
+    })
+        .await
+        .unwrap();
+    assert_eq!(n, 3);
+}
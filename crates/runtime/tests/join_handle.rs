@@ -0,0 +1,34 @@
+use core::time::Duration;
+use everywhere_runtime::{task::{self, JoinError}, time};
+use everywhere_test::cross_test;
+
+/// Awaiting the handle yields the task's output.
+#[cross_test]
+async fn join_handle_returns_output() {
+    let h = task::spawn(async {
+        time::sleep(Duration::from_millis(5)).await;
+        21 * 2
+    });
+    assert_eq!(h.await, Ok(42));
+}
+
+/// `abort()` stops a pending task and reports `Cancelled`.
+#[cross_test]
+async fn join_handle_abort() {
+    let h = task::spawn(async {
+        time::sleep(Duration::from_secs(10)).await;
+        1
+    });
+    assert!(!h.is_finished());
+
+    h.abort();
+    assert_eq!(h.await, Err(JoinError::Cancelled));
+}
+
+/// `is_finished()` flips once the task has run to completion.
+#[cross_test]
+async fn join_handle_is_finished() {
+    let h = task::spawn(async {});
+    time::sleep(Duration::from_millis(10)).await;
+    assert!(h.is_finished());
+}
@@ -0,0 +1,131 @@
+use core::time::Duration;
+use everywhere_runtime::{metrics, prelude::*, task, time};
+use everywhere_test::cross_test;
+
+/// Named tasks are counted while alive and once they complete.
+#[cross_test]
+async fn tasks_are_counted_by_name() {
+    let (tx, rx) = task::oneshot::<u32>();
+    let h = task::TaskBuilder::new().name("metrics-worker").spawn(async move { rx.await.unwrap() });
+
+    let t = metrics::snapshot().task("metrics-worker").cloned().unwrap();
+    assert_eq!((t.spawned, t.alive, t.completed), (1, 1, 0));
+
+    tx.send(5).unwrap();
+    assert_eq!(h.await.unwrap(), 5);
+
+    let t = metrics::snapshot().task("metrics-worker").cloned().unwrap();
+    assert_eq!((t.spawned, t.alive, t.completed), (1, 0, 1));
+
+    task::spawn(async {}).await.unwrap();
+    assert!(metrics::snapshot().task(metrics::UNNAMED).unwrap().completed >= 1);
+}
+
+/// Blocking tasks are counted like any other spawn.
+#[cfg(not(target_arch = "wasm32"))]
+#[cross_test]
+async fn blocking_tasks_are_counted() {
+    let (tx, rx) = std::sync::mpsc::channel::<()>();
+    let h = task::TaskBuilder::new().name("metrics-blocking").spawn_blocking(move || rx.recv().unwrap());
+
+    let t = metrics::snapshot().task("metrics-blocking").cloned().unwrap();
+    assert_eq!((t.spawned, t.alive, t.completed), (1, 1, 0));
+
+    tx.send(()).unwrap();
+    h.await.unwrap();
+    let t = metrics::snapshot().task("metrics-blocking").cloned().unwrap();
+    assert_eq!((t.spawned, t.alive, t.completed), (1, 0, 1));
+}
+
+/// Depth follows sends and receives; dropped channels disappear.
+#[cross_test]
+async fn channel_depth_is_tracked() {
+    const CAP: usize = 13;   // unlikely to clash with other tests' channels
+    let depth = || {
+        metrics::snapshot().channels.iter().find(|c| c.capacity == Some(CAP)).map(|c| c.depth)
+    };
+
+    let (mut tx, mut rx) = task::channel_bounded::<u32>(CAP);
+    for i in 0..3 { tx.send(i).await.unwrap(); }
+    assert_eq!(depth(), Some(3));
+
+    rx.recv().await.unwrap();
+    assert_eq!(rx.try_recv(), Ok(1));
+    assert_eq!(depth(), Some(1));
+
+    drop((tx, rx));
+    assert_eq!(depth(), None);
+}
+
+/// Sleeps are counted and their lateness lands in the histogram.
+#[cross_test]
+async fn sleeps_are_counted() {
+    let before = metrics::snapshot().timers;
+    time::sleep(Duration::from_millis(5)).await;
+    let after = metrics::snapshot().timers;
+
+    assert!(after.created > before.created);
+    assert!(after.fired > before.fired);
+    assert!(after.overshoot.count > before.overshoot.count);
+    assert_eq!(after.overshoot.buckets.len(), metrics::OVERSHOOT_BOUNDS.len() + 1);
+    assert_eq!(after.overshoot.buckets.iter().sum::<u64>(), after.overshoot.count);
+}
+
+/// Broadcast depth is the backlog of the receiver furthest behind.
+#[cross_test]
+async fn broadcast_depth_follows_slowest_receiver() {
+    let find = || {
+        metrics::snapshot().channels.into_iter()
+            .find(|c| c.kind == metrics::ChannelKind::Broadcast && c.capacity == Some(64))
+    };
+
+    let (tx, mut fast) = task::broadcast::<u32>(64);
+    let mut slow = tx.subscribe();
+    for i in 0..3 { tx.send(i).unwrap(); }
+    for _ in 0..3 { fast.recv().await.unwrap(); }
+    slow.recv().await.unwrap();
+
+    let c = find().unwrap();
+    assert_eq!((c.depth, c.sent), (2, 3));
+
+    drop(slow);
+    assert_eq!(find().unwrap().depth, 0);
+    drop((tx, fast));
+    assert!(find().is_none());
+}
+
+/// A watch channel is one deep until every receiver has seen the value.
+#[cross_test]
+async fn watch_depth_is_unseen_value() {
+    let find = |sent| {
+        metrics::snapshot().channels.into_iter()
+            .find(|c| c.kind == metrics::ChannelKind::Watch && c.sent == sent)
+            .map(|c| c.depth)
+    };
+
+    let (tx, mut rx) = task::watch(0u32);
+    for i in 1..=7 { tx.send(i).unwrap(); }
+    assert_eq!(find(7), Some(1));
+
+    rx.changed().await.unwrap();
+    let mut copy = rx.clone();
+    assert_eq!(find(7), Some(0));
+
+    tx.send_replace(8);
+    copy.borrow_and_update();
+    assert_eq!(find(8), Some(1));                // `rx` has not looked yet
+    rx.changed().await.unwrap();
+    assert_eq!(find(8), Some(0));
+}
+
+/// The mean holds up past `u32::MAX` samples.
+#[cross_test]
+async fn histogram_mean_of_many_samples() {
+    let h = metrics::Histogram {
+        count: 1 << 33,
+        sum: Duration::from_secs(3 << 33),
+        ..Default::default()
+    };
+    assert_eq!(h.mean(), Some(Duration::from_secs(3)));
+    assert_eq!(metrics::Histogram::default().mean(), None);
+}
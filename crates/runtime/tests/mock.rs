@@ -0,0 +1,76 @@
+use core::time::Duration;
+use everywhere_runtime::{task, time, MockRt};
+use everywhere_test::cross_test;
+use std::sync::{Arc, Mutex};
+
+const MS: Duration = Duration::from_millis(1);
+
+/// `block_on` jumps the clock instead of sleeping.
+#[cross_test]
+async fn block_on_auto_advances() {
+    let real = std::time::Instant::now();
+    let start = time::now_with::<MockRt>();
+
+    MockRt::block_on(time::sleep_with::<MockRt>(Duration::from_secs(3600)));
+
+    assert_eq!(time::now_with::<MockRt>() - start, Duration::from_secs(3600));
+    assert!(real.elapsed() < Duration::from_secs(1));
+}
+
+/// Spawned tasks only see time move through `advance`.
+#[cross_test]
+async fn advance_drives_spawned_sleepers() {
+    let h = task::spawn_with::<MockRt, _>(async {
+        time::sleep_with::<MockRt>(10 * MS).await;
+        42
+    });
+
+    MockRt::run_until_stalled();
+    assert!(!h.is_finished());
+    MockRt::advance(9 * MS);
+    assert!(!h.is_finished());
+    MockRt::advance(MS);
+    assert!(h.is_finished());
+    assert_eq!(MockRt::block_on(h), Ok(42));
+}
+
+/// Timers fire in deadline order, whatever the spawn order.
+#[cross_test]
+async fn timers_fire_in_deadline_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    for ms in [30, 10, 20] {
+        let log = log.clone();
+        task::spawn_with::<MockRt, _>(async move {
+            time::sleep_with::<MockRt>(ms * MS).await;
+            log.lock().unwrap().push(ms);
+        });
+    }
+
+    MockRt::advance(30 * MS);
+    assert_eq!(*log.lock().unwrap(), [10, 20, 30]);
+}
+
+/// Timeouts and intervals are exact on the virtual clock.
+#[cross_test]
+async fn timeout_and_interval_are_deterministic() {
+    MockRt::block_on(async {
+        let slow = time::sleep_with::<MockRt>(20 * MS);
+        assert!(time::timeout_with::<MockRt, _>(10 * MS, slow).await.is_err());
+
+        let mut iv = time::interval_with::<MockRt>(5 * MS);
+        let t0 = iv.tick().await;
+        for n in 1..=3 { assert_eq!(iv.tick().await, t0 + n * 5 * MS); }
+        assert_eq!(time::now_with::<MockRt>(), t0 + 15 * MS);
+    });
+}
+
+/// A task the executor drops unfinished – here with its thread – reports
+/// `Cancelled`, not `Panicked`.
+#[cfg(not(target_arch = "wasm32"))]
+#[cross_test]
+async fn dropped_task_is_cancelled() {
+    let h = std::thread::spawn(|| {
+        task::spawn_with::<MockRt, _>(core::future::pending::<()>())
+    }).join().unwrap();
+    assert_eq!(h.await, Err(task::JoinError::Cancelled));
+}
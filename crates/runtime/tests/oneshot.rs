@@ -0,0 +1,18 @@
+use everywhere_runtime::{prelude::*, task, RecvError, SendError};
+use everywhere_test::cross_test;
+
+/// Reply travels back; a dropped end is reported on the other.
+#[cross_test]
+async fn oneshot_reply_and_drop() {
+    let (tx, rx) = task::oneshot::<u32>();
+    task::spawn(async move { let _ = tx.send(7); });
+    assert_eq!(rx.await, Ok(7));
+
+    let (tx, rx) = task::oneshot::<u32>();
+    drop(tx);
+    assert_eq!(rx.await, Err(RecvError));
+
+    let (tx, rx) = task::oneshot::<u32>();
+    drop(rx);
+    assert_eq!(tx.send(1), Err(SendError(1)));
+}
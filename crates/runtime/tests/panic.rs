@@ -0,0 +1,61 @@
+// wasm32 targets abort on panic, so there is nothing to catch there.
+#![cfg(not(target_arch = "wasm32"))]
+
+use everywhere_runtime::task::{self, JoinError, TaskBuilder};
+use everywhere_test::cross_test;
+use std::sync::{Mutex, Once};
+
+static REPORTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn capture_reports() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        task::set_panic_handler(|r| REPORTS.lock().unwrap().push(r.to_string()));
+    });
+}
+
+fn reported(line: &str) -> bool { REPORTS.lock().unwrap().iter().any(|r| r == line) }
+
+/// A panicking task fails its handle, not the runtime.
+#[cross_test]
+async fn plain_spawn_isolates_panic() {
+    let h = task::spawn(async { panic!("boom") });
+    assert_eq!(h.await.unwrap_err(), JoinError::Panicked);
+
+    // The runtime keeps going.
+    assert_eq!(task::spawn(async { 1 }).await.unwrap(), 1);
+}
+
+/// Named tasks report the panic with their name.
+#[cross_test]
+async fn named_task_reports_panic() {
+    capture_reports();
+
+    let h = TaskBuilder::new().name("reader").spawn(async { panic!("socket gone") });
+    assert_eq!(h.await.unwrap_err(), JoinError::Panicked);
+    assert!(reported("task `reader` panicked: socket gone"));
+}
+
+/// `spawn_catching` reports formatted messages for unnamed tasks.
+#[cross_test]
+async fn spawn_catching_reports_unnamed() {
+    capture_reports();
+
+    let id = 7;
+    let h = task::spawn_catching(async move { panic!("bad id {id}") });
+    assert!(h.await.unwrap_err().is_panic());
+    assert!(reported("task panicked: bad id 7"));
+}
+
+/// A task that finishes normally reports nothing and keeps its value.
+#[cross_test]
+async fn builder_passes_output_through() {
+    let h = TaskBuilder::new().name("adder").spawn(async { 2 + 2 });
+    assert_eq!(h.await.unwrap(), 4);
+
+    let out = task::run_local(async {
+        TaskBuilder::new().name("local").spawn_local(async { "ok" }).await
+    })
+        .await;
+    assert_eq!(out, Ok("ok"));
+}
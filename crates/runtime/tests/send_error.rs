@@ -0,0 +1,39 @@
+use everywhere_runtime::{prelude::*, task, RecvError, SendError, TrySendError};
+use everywhere_test::cross_test;
+use std::error::Error;
+
+/// An unbounded send to a dropped receiver hands the value back.
+#[cross_test]
+async fn unbounded_send_returns_value() {
+    let (tx, rx) = task::channel::<String>();
+    drop(rx);
+
+    let err = tx.send_owned("lost?".to_owned()).unwrap_err();
+    assert_eq!(err.into_inner(), "lost?");
+}
+
+/// Watch and oneshot agree on the same error type.
+#[cross_test]
+async fn every_sender_uses_send_error() {
+    let (tx, rx) = task::watch(0u8);
+    drop(rx);
+    assert_eq!(tx.send(1), Err(SendError(1)));
+
+    let (tx, rx) = task::oneshot::<u8>();
+    drop(rx);
+    assert_eq!(tx.send(2).unwrap_err().0, 2);
+}
+
+/// The errors are `std::error::Error`s, so `?` converts them.
+#[cross_test]
+async fn errors_box_into_dyn_error() {
+    fn boxed<E: Error + 'static>(e: E) -> Box<dyn Error> { Box::new(e) }
+
+    assert_eq!(boxed(SendError(1)).to_string(), "channel closed");
+    assert_eq!(boxed(TrySendError::Full(1)).to_string(), "channel full");
+    assert_eq!(boxed(RecvError).to_string(), "channel closed");
+
+    let closed: TrySendError<u8> = SendError(3).into();
+    assert!(closed.is_closed());
+    assert_eq!(closed.into_inner(), 3);
+}
@@ -0,0 +1,37 @@
+// Process signals only exist on unix hosts.
+#![cfg(all(unix, not(target_arch = "wasm32")))]
+
+use core::time::Duration;
+use everywhere_runtime::{
+    signal::{self, Shutdown},
+    time::FutureExt,
+};
+use everywhere_test::cross_test;
+use futures_util::StreamExt;
+use std::process::Command;
+
+fn raise(sig: &str) {
+    let pid = std::process::id().to_string();
+    assert!(Command::new("kill").args([sig, &pid]).status().unwrap().success());
+}
+
+/// One test, so the two signals cannot race each other.
+#[cross_test]
+async fn streams_and_shutdown_see_signals() {
+    let mut hup = signal::sighup().unwrap();
+    raise("-HUP");
+    hup.next().timeout(Duration::from_secs(5)).await.expect("no SIGHUP");
+
+    let shutdown = signal::shutdown();
+    let mut shutdown = core::pin::pin!(shutdown.timeout(Duration::from_secs(5)));
+    // Let `shutdown` install its handlers before the signal goes out.
+    assert!(futures_util::FutureExt::now_or_never(shutdown.as_mut()).is_none());
+    raise("-TERM");
+    assert_eq!(shutdown.await, Ok(Shutdown::Terminate));
+}
+
+/// Nothing fires on its own.
+#[cross_test]
+async fn ctrl_c_waits() {
+    assert!(signal::ctrl_c().timeout(Duration::from_millis(20)).await.is_err());
+}
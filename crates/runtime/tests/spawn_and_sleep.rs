@@ -1,5 +1,5 @@
 use core::{sync::atomic::{AtomicU8, Ordering}, time::Duration};
-use everywhere_runtime::{prelude::*, task, time};
+use everywhere_runtime::{task, time};
 use everywhere_test::cross_test;
 use std::sync::Arc;
 
@@ -0,0 +1,29 @@
+use everywhere_runtime::task;
+use everywhere_test::cross_test;
+use std::{cell::RefCell, rc::Rc};
+
+/// `!Send` state may cross `.await` in a local task.
+#[cross_test]
+async fn spawn_local_runs_non_send_future() {
+    let out = task::run_local(async {
+        let shared = Rc::new(RefCell::new(Vec::new()));
+        let s = shared.clone();
+        let h = task::spawn_local(async move {
+            s.borrow_mut().push(1);
+            task::yield_now().await;
+            s.borrow_mut().push(2);
+            s.borrow().len()
+        });
+        assert_eq!(h.await.unwrap(), 2);
+        shared.take()
+    })
+        .await;
+    assert_eq!(out, [1, 2]);
+}
+
+/// Blocking work hands its result back through the handle.
+#[cross_test]
+async fn spawn_blocking_returns_value() {
+    let sum = task::spawn_blocking(|| (1..=100u64).sum::<u64>()).await.unwrap();
+    assert_eq!(sum, 5050);
+}
@@ -0,0 +1,187 @@
+use core::{pin::pin, time::Duration};
+use everywhere_runtime::{
+    sync::{
+        MappedMutexGuard, Mutex, MutexGuard, Notify, OwnedMappedMutexGuard, OwnedMutexGuard,
+        RwLock, Semaphore, TryAcquireError,
+    },
+    task, time, TaskGroup,
+};
+use everywhere_test::cross_test;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Guards survive `.await`; the read‑modify‑write never interleaves.
+#[cross_test]
+async fn mutex_serialises_tasks() {
+    let m = Arc::new(Mutex::new(0u32));
+    let mut group = TaskGroup::new();
+    for _ in 0..10 {
+        let m = m.clone();
+        group.spawn(async move {
+            let mut g = m.lock().await;
+            let v = *g;
+            task::yield_now().await;
+            *g = v + 1;
+        });
+    }
+    group.join_all().await;
+    assert_eq!(*m.lock().await, 10);
+
+    let g = m.clone().lock_owned().await;
+    assert!(m.try_lock().is_err());
+    drop(g);
+    assert!(m.try_lock().is_ok());
+}
+
+/// Readers share, a writer excludes.
+#[cross_test]
+async fn rwlock_readers_share_writer_excludes() {
+    let l = RwLock::new(String::from("a"));
+    {
+        let r1 = l.read().await;
+        let r2 = l.try_read().unwrap();
+        assert_eq!((r1.as_str(), r2.as_str()), ("a", "a"));
+        assert!(l.try_write().is_err());
+    }
+    l.write().await.push('b');
+    let w = l.try_write().unwrap();
+    assert!(l.try_read().is_err());
+    drop(w);
+    assert_eq!(l.into_inner(), "ab");
+}
+
+/// Owned guards keep the lock alive and move into tasks.
+#[cross_test]
+async fn rwlock_owned_guards_travel() {
+    let l = Arc::new(RwLock::new(1));
+    let mut w = l.clone().write_owned().await;
+    assert!(l.clone().try_read_owned().is_err());
+
+    let reader = task::spawn({
+        let l = l.clone();
+        async move { *l.read_owned().await }
+    });
+    *w += 1;
+    drop(w);
+    assert_eq!(reader.await.unwrap(), 2);
+
+    let r = l.clone().try_read_owned().unwrap();
+    assert!(l.clone().try_write_owned().is_err());
+    drop(r);
+    *l.try_write_owned().unwrap() = 3;
+}
+
+/// Owned permits cap concurrency and travel into tasks.
+#[cross_test]
+async fn semaphore_limits_concurrency() {
+    let sem = Arc::new(Semaphore::new(2));
+    let live = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+
+    let mut group = TaskGroup::new();
+    for _ in 0..5 {
+        let permit = sem.clone().acquire_owned().await.unwrap();
+        let (live, peak) = (live.clone(), peak.clone());
+        group.spawn(async move {
+            let now = live.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            time::sleep(Duration::from_millis(5)).await;
+            live.fetch_sub(1, Ordering::SeqCst);
+            drop(permit);
+        });
+    }
+    group.join_all().await;
+    assert_eq!(peak.load(Ordering::SeqCst), 2);
+    assert_eq!(sem.available_permits(), 2);
+
+    let held = sem.acquire_many(2).await.unwrap();
+    assert_eq!(sem.try_acquire().unwrap_err(), TryAcquireError::NoPermits);
+    drop(held);
+
+    sem.close();
+    assert!(sem.acquire().await.is_err());
+    assert_eq!(sem.try_acquire().unwrap_err(), TryAcquireError::Closed);
+}
+
+/// `notify_one` stores a permit; `notify_waiters` wakes everyone waiting.
+#[cross_test]
+async fn notify_one_and_waiters() {
+    let n = Arc::new(Notify::new());
+    n.notify_one();
+    n.notified().await;        /* consumes the stored permit */
+
+    let woken = Arc::new(AtomicUsize::new(0));
+    let mut group = TaskGroup::new();
+    for _ in 0..3 {
+        let (n, woken) = (n.clone(), woken.clone());
+        group.spawn(async move {
+            n.notified().await;
+            woken.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(woken.load(Ordering::SeqCst), 0);
+
+    n.notify_waiters();
+    group.join_all().await;
+    assert_eq!(woken.load(Ordering::SeqCst), 3);
+}
+
+/// `const_new` statics and mapped guards behave as Tokio's do.
+#[cross_test]
+async fn mutex_guards_map() {
+    static LIST: Mutex<Vec<u32>> = Mutex::const_new(Vec::new());
+    LIST.lock().await.push(1);
+
+    let mut first = MutexGuard::map(LIST.lock().await, |v| &mut v[0]);
+    *first += 1;
+    assert!(LIST.try_lock().is_err());
+    let first = MappedMutexGuard::try_map(first, |_| None::<&mut u32>).unwrap_err();
+    drop(first);
+    assert!(MutexGuard::try_map(LIST.lock().await, |v| v.get_mut(5)).is_err());
+    assert_eq!(*LIST.lock().await, [2]);
+
+    let m = Arc::new(Mutex::new((1u8, String::from("a"))));
+    let owned = m.clone().lock_owned().await;
+    assert!(Arc::ptr_eq(OwnedMutexGuard::mutex(&owned), &m));
+    let mut name: OwnedMappedMutexGuard<_, String> = OwnedMutexGuard::map(owned, |p| &mut p.1);
+    name.push('b');
+    assert!(m.try_lock().is_err());
+    drop(name);
+    assert_eq!(m.lock().await.1, "ab");
+}
+
+/// `blocking_lock` from plain threads, outside any runtime.
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn mutex_blocking_lock() {
+    let m = Arc::new(Mutex::new(0u32));
+    let held = m.blocking_lock();
+    let waiter = {
+        let m = m.clone();
+        std::thread::spawn(move || {
+            let owned = *m.clone().blocking_lock_owned();
+            owned + *m.blocking_lock()
+        })
+    };
+    std::thread::sleep(Duration::from_millis(10));
+    drop(held);
+    assert_eq!(waiter.join().unwrap(), 0);
+}
+
+/// An enabled `Notified` is picked by `notify_one` instead of a permit
+/// being stored.
+#[cross_test]
+async fn notified_enable_joins_the_queue() {
+    static NOTIFY: Notify = Notify::const_new();
+    let mut first = pin!(NOTIFY.notified());
+    assert!(!first.as_mut().enable());
+
+    NOTIFY.notify_one();
+    let mut second = pin!(NOTIFY.notified());
+    assert!(!second.as_mut().enable());          /* no permit was left behind */
+    assert!(first.as_mut().enable());
+    first.await;
+}
@@ -0,0 +1,61 @@
+use core::{sync::atomic::{AtomicBool, Ordering}, time::Duration};
+use everywhere_runtime::{task::{JoinError, TaskGroup}, time};
+use everywhere_test::cross_test;
+use std::sync::Arc;
+
+async fn nap(ms: u64) { time::sleep(Duration::from_millis(ms)).await; }
+
+/// `join_next` hands results back in the order children finish.
+#[cross_test]
+async fn task_group_completion_order() {
+    let mut g = TaskGroup::new();
+    g.spawn(async { nap(60).await; 1 });
+    g.spawn(async { nap(5).await; 2 });
+    g.spawn(async { nap(30).await; 3 });
+
+    assert_eq!(g.join_next().await, Some(Ok(2)));
+    assert_eq!(g.join_next().await, Some(Ok(3)));
+    assert_eq!(g.join_next().await, Some(Ok(1)));
+    assert_eq!(g.join_next().await, None);
+}
+
+/// Dropping the group cancels children that are still running.
+#[cross_test]
+async fn task_group_drop_cancels() {
+    let ran = Arc::new(AtomicBool::new(false));
+    let r = ran.clone();
+
+    let mut g = TaskGroup::new();
+    g.spawn(async move { nap(30).await; r.store(true, Ordering::SeqCst); });
+    drop(g);
+
+    nap(60).await;
+    assert!(!ran.load(Ordering::SeqCst));
+}
+
+/// In fail‑fast mode the first `Err` aborts the siblings.
+#[cross_test]
+async fn task_group_fail_fast() {
+    let ran = Arc::new(AtomicBool::new(false));
+    let r = ran.clone();
+
+    let mut g = TaskGroup::<Result<u32, &str>>::new().fail_fast();
+    g.spawn(async { nap(5).await; Err("boom") });
+    g.spawn(async move { nap(40).await; r.store(true, Ordering::SeqCst); Ok(1) });
+
+    assert_eq!(g.join_next().await, Some(Ok(Err("boom"))));
+    assert_eq!(g.join_next().await, Some(Err(JoinError::Cancelled)));
+    nap(60).await;
+    assert!(!ran.load(Ordering::SeqCst));
+}
+
+/// `join_all` drains every child.
+#[cross_test]
+async fn task_group_join_all() {
+    let mut g = TaskGroup::new();
+    for i in 0..4u32 { g.spawn(async move { nap(u64::from(i)).await; i }); }
+
+    let mut out: Vec<u32> = g.join_all().await.into_iter().map(Result::unwrap).collect();
+    out.sort();
+    assert_eq!(out, [0, 1, 2, 3]);
+}
@@ -0,0 +1,106 @@
+use core::time::Duration;
+use everywhere_runtime::{task, task_local, time};
+use everywhere_test::cross_test;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Once,
+};
+
+task_local! {
+    static REQUEST_ID: u64;
+    static USER: String;
+}
+
+task_local! {
+    /// Inherited by spawned tasks (see `spawned_task_inherits_value`).
+    static TRACE_ID: u32;
+}
+
+/// The value is visible for the extent of the scope only.
+#[cross_test]
+async fn scope_sets_value() {
+    assert!(REQUEST_ID.try_with(|_| ()).is_err());
+
+    let seen = REQUEST_ID.scope(7, async { REQUEST_ID.get() }).await;
+    assert_eq!(seen, 7);
+
+    assert!(REQUEST_ID.try_with(|_| ()).is_err());
+}
+
+/// Inner scopes shadow outer ones and restore them on exit.
+#[cross_test]
+async fn nested_scopes_shadow() {
+    REQUEST_ID
+        .scope(1, async {
+            REQUEST_ID.scope(2, async { assert_eq!(REQUEST_ID.get(), 2) }).await;
+            assert_eq!(REQUEST_ID.get(), 1);
+            USER.scope("ada".into(), async {
+                assert_eq!(REQUEST_ID.get(), 1);
+                USER.with(|u| assert_eq!(u, "ada"));
+            })
+                .await;
+        })
+        .await;
+}
+
+/// The value survives suspension points, even on a multi‑threaded runtime.
+#[cross_test]
+async fn value_follows_across_awaits() {
+    let h = task::spawn(REQUEST_ID.scope(99, async {
+        task::yield_now().await;
+        time::sleep(Duration::from_millis(5)).await;
+        REQUEST_ID.get()
+    }));
+    assert_eq!(h.await.unwrap(), 99);
+}
+
+/// `sync_scope` works for plain closures.
+#[cross_test]
+async fn sync_scope_in_closure() {
+    assert_eq!(REQUEST_ID.sync_scope(3, || REQUEST_ID.get() + 1), 4);
+    assert_eq!(REQUEST_ID.try_with(|_| ()).unwrap_err().to_string(), "task-local value not set");
+}
+
+/// Keys that opt in are carried into spawned tasks.
+#[cross_test]
+async fn spawned_task_inherits_value() {
+    static INHERIT: Once = Once::new();
+    INHERIT.call_once(|| TRACE_ID.inherit_on_spawn());
+
+    let h = TRACE_ID.sync_scope(5, || task::spawn(async { TRACE_ID.try_with(|v| *v).ok() }));
+    assert_eq!(h.await.unwrap(), Some(5));
+
+    let h = task::spawn(async { TRACE_ID.try_with(|v| *v).ok() });
+    assert_eq!(h.await.unwrap(), None);
+}
+
+/// Blocking closures see the spawner's inherited keys too.
+#[cfg(not(target_arch = "wasm32"))]
+#[cross_test]
+async fn spawn_blocking_inherits_value() {
+    static INHERIT: Once = Once::new();
+    INHERIT.call_once(|| TRACE_ID.inherit_on_spawn());
+
+    let h = TRACE_ID.sync_scope(8, || task::spawn_blocking(|| TRACE_ID.try_with(|v| *v).ok()));
+    assert_eq!(h.await.unwrap(), Some(8));
+}
+
+/// Custom hooks capture in the spawner and wrap every poll of the child.
+#[cross_test]
+async fn spawn_hook_wraps_polls() {
+    static POLLS: AtomicUsize = AtomicUsize::new(0);
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        task::add_spawn_hook(|| {
+            USER.try_with(|u| u == "hooked").ok()?.then_some(())?;
+            Some(Box::new(|poll: &mut dyn FnMut()| {
+                POLLS.fetch_add(1, Ordering::SeqCst);
+                poll()
+            }))
+        })
+    });
+
+    let h = USER.sync_scope("hooked".into(), || task::spawn(async { task::yield_now().await }));
+    h.await.unwrap();
+    assert!(POLLS.load(Ordering::SeqCst) >= 2);
+}
@@ -1,8 +1,8 @@
 use core::time::Duration;
-use everywhere_runtime::{time, prelude::*};
+use everywhere_runtime::time::{self, Elapsed, FutureExt};
 use everywhere_test::cross_test;
 
-/// Exercises `Rt::timeout`.
+/// Exercises `time::timeout`.
 #[cross_test]
 async fn timeout_behaviour() {
     /* fast path – must succeed */
@@ -28,3 +28,34 @@ async fn timeout_behaviour() {
         .await;
     assert!(res.is_err());
 }
+
+/// Plain outputs and borrowed state are fine; `Elapsed` is a real error.
+#[cross_test]
+async fn timeout_any_output_with_borrows() {
+    let mut hits = 0;
+    let n = time::timeout(Duration::from_millis(100), async {
+        hits += 1;
+        7
+    })
+        .await;
+    assert_eq!(n, Ok(7));
+    assert_eq!(hits, 1);
+
+    let err: Elapsed = time::sleep(Duration::from_millis(100))
+        .timeout(Duration::from_millis(5))
+        .await
+        .unwrap_err();
+    let _: &dyn std::error::Error = &err;
+    assert_eq!(err.to_string(), "deadline has elapsed");
+}
+
+/// The deadline is fixed at creation, not at first poll.
+#[cross_test]
+async fn timeout_deadline_starts_at_creation() {
+    let pending = futures_util::future::pending::<()>().timeout(Duration::from_millis(10));
+    time::sleep(Duration::from_millis(20)).await;
+
+    let before = time::now();
+    assert!(pending.await.is_err());
+    assert!(before.elapsed() < Duration::from_millis(10));
+}
@@ -0,0 +1,20 @@
+use everywhere_runtime::task;
+use everywhere_test::cross_test;
+use tracing::{info_span, Span};
+use tracing_subscriber::Registry;
+
+/// A spawned task runs inside the span that was current at `spawn`.
+#[cross_test]
+async fn spawn_propagates_current_span() {
+    // Global so that worker threads see the same subscriber.
+    tracing::subscriber::set_global_default(Registry::default()).unwrap();
+
+    let parent = info_span!("request");
+    let id = parent.id();
+
+    let h = parent.in_scope(|| task::spawn(async { Span::current().id() }));
+    assert_eq!(h.await.unwrap(), id);
+
+    // Outside the span it is not carried over.
+    assert_ne!(task::spawn(async { Span::current().id() }).await.unwrap(), id);
+}
@@ -0,0 +1,28 @@
+use core::time::Duration;
+use everywhere_runtime::{prelude::*, task, time};
+use everywhere_test::cross_test;
+
+/// Receivers only see the newest value, once, and learn when the sender dies.
+#[cross_test]
+async fn watch_tracks_latest() {
+    let (tx, mut rx) = task::watch("idle");
+    assert_eq!(*rx.borrow(), "idle");
+    assert_eq!(rx.has_changed(), Ok(false));
+
+    tx.send("connecting").unwrap();
+    tx.send("open").unwrap();
+    rx.changed().await.unwrap();
+    assert_eq!(*rx.borrow_and_update(), "open");
+    assert_eq!(rx.has_changed(), Ok(false));
+
+    let mut late = tx.subscribe();
+    assert_eq!(late.has_changed(), Ok(false));
+
+    task::spawn(async move {
+        time::sleep(Duration::from_millis(10)).await;
+        let _ = tx.send("closed");
+    });
+    late.changed().await.unwrap();
+    assert_eq!(*late.borrow(), "closed");
+    assert!(late.changed().await.is_err());
+}
@@ -76,7 +76,9 @@ pub fn cross_test(attr: TokenStream, item: TokenStream) -> TokenStream {
     };
 
     /*── attribute paths ───────────────────────────────────────────*/
-    let tokio_attr     = quote!(#root::__rt::tokio::test);
+    /* `crate = …` so callers need no direct `tokio` dependency */
+    let tokio_crate    = format!("{root}::__rt::tokio").replace(' ', "");
+    let tokio_attr     = quote!(#root::__rt::tokio::test(crate = #tokio_crate));
     let wasm_attr      = quote!(#root::__rt::wasm_bindgen_test::wasm_bindgen_test);
     let async_std_attr = quote!(#root::__rt::async_std::test);
 
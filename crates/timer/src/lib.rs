@@ -23,16 +23,17 @@ use std::sync::Arc;
 // use alloc::sync::Arc;
 
 use core::fmt::Debug;
+use core::future::Future;
+use core::marker::PhantomData;
 use core::{
     sync::atomic::{AtomicUsize, Ordering},
     time::Duration,
 };
-use everywhere_runtime::{task, time, Rt, _RtTrait as Runtime};
-use spin::Mutex;
+use everywhere_runtime::{prelude::Runtime, sync::Mutex, task::{self, TaskGroup}, MaybeSend, Rt};
 // glue only!
 
 /*──────────── handle alias ───────────*/
-type Handle = <Rt as Runtime>::JoinHandle;
+type Handle = task::JoinHandle<()>;
 pub type TimerHandle = Handle;
 
 /*──────────── timer struct ───────────*/
@@ -41,13 +42,26 @@ type Calc = dyn Fn(usize) -> Duration + Send + Sync + 'static;
 type Cb = dyn FnMut() + Send + Sync + 'static;
 
 /// Clone‑able timer with a shared retry counter.
-#[derive(Clone)]
-pub struct Timer {
+///
+/// Sleeps and spawns on `R` – the crate‑level [`Rt`] unless a test swaps
+/// in a virtual‑time runtime through [`Timer::new_with`].
+pub struct Timer<R: Runtime = Rt> {
     tries: Arc<AtomicUsize>,
     calc: Arc<Calc>,
     cb: Arc<Mutex<Box<Cb>>>,
+    _rt: PhantomData<fn() -> R>,
 }
-impl Debug for Timer {
+impl<R: Runtime> Clone for Timer<R> {
+    fn clone(&self) -> Self {
+        Self {
+            tries: self.tries.clone(),
+            calc: self.calc.clone(),
+            cb: self.cb.clone(),
+            _rt: PhantomData,
+        }
+    }
+}
+impl<R: Runtime> Debug for Timer<R> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Timer")
             .field("tries", &self.tries.load(Ordering::Relaxed))
@@ -56,8 +70,19 @@ impl Debug for Timer {
 }
 
 impl Timer {
-    /// Create a new timer.
+    /// Create a new timer on the crate‑level runtime.
     pub fn new<C, F>(cb: C, calc: F) -> Self
+    where
+        C: FnMut() + Send + Sync + 'static,
+        F: Fn(usize) -> Duration + Send + Sync + 'static,
+    {
+        Self::new_with(cb, calc)
+    }
+}
+
+impl<R: Runtime> Timer<R> {
+    /// Create a new timer on any `R: Runtime`.
+    pub fn new_with<C, F>(cb: C, calc: F) -> Self
     where
         C: FnMut() + Send + Sync + 'static,
         F: Fn(usize) -> Duration + Send + Sync + 'static,
@@ -66,6 +91,7 @@ impl Timer {
             tries: Arc::new(AtomicUsize::new(0)),
             calc: Arc::new(calc),
             cb: Arc::new(Mutex::new(Box::new(cb))),
+            _rt: PhantomData,
         }
     }
 
@@ -76,16 +102,26 @@ impl Timer {
     pub fn tries(&self) -> usize { self.tries.load(Ordering::Relaxed) }
 
     /// Sleep once for the base delay (handy for jitter tests).
-    pub async fn sleep(&self) { time::sleep((self.calc)(0)).await; }
+    pub async fn sleep(&self) { R::sleep((self.calc)(0)).await; }
 
     /// Schedule the callback after the next back‑off interval.
-    pub fn schedule_timeout(&self) -> Handle {
+    pub fn schedule_timeout(&self) -> Handle { R::spawn(self.next_fire()) }
+
+    /// Like [`schedule_timeout`](Self::schedule_timeout), but the pending
+    /// callback belongs to `group` and is cancelled when the group drops.
+    pub fn schedule_timeout_in(&self, group: &mut TaskGroup<()>) {
+        group.spawn_with::<R, _>(self.next_fire());
+    }
+
+    /// Bump the retry counter and build the sleep‑then‑fire future.
+    fn next_fire(&self) -> impl Future<Output = ()> + MaybeSend + 'static {
         let n = self.tries.fetch_add(1, Ordering::Relaxed) + 1;
         let calc = self.calc.clone();
         let cb = self.cb.clone();
-        task::spawn(async move {
-            time::sleep((calc)(n)).await;
-            if let Some(mut g) = cb.try_lock() { (g)(); }
-        })
+        async move {
+            R::sleep((calc)(n)).await;
+            /* overlapping fires queue up instead of being dropped */
+            (cb.lock().await)();
+        }
     }
 }
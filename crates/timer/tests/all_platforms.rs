@@ -1,30 +1,41 @@
 //! Comprehensive tests for `cross‑timer` on every runtime.
+//!
+//! Timing‑sensitive cases run on [`MockRt`]: virtual time makes them
+//! exact and instant instead of racing real sleeps.
 
 use core::{
     sync::atomic::{AtomicUsize, Ordering},
     time::Duration,
 };
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use everywhere_runtime::time;
+use everywhere_runtime::{task::TaskGroup, time, MockRt};
 use everywhere_test::cross_test;
 use everywhere_timer::Timer;
 
-/// Convenience awaitable sleep.
-async fn wait(ms: u64) { time::sleep(Duration::from_millis(ms)).await; }
+type MockTimer = Timer<MockRt>;
 
-/*──────────── single‑shot ───────────*/
+const MS: Duration = Duration::from_millis(1);
 
-#[cross_test]
-async fn fires_exactly_once() {
+/// Counting callback plus its counter.
+fn counter() -> (Arc<AtomicUsize>, impl FnMut() + Send + Sync + 'static) {
     let hit = Arc::new(AtomicUsize::new(0));
     let h = hit.clone();
+    (hit, move || { h.fetch_add(1, Ordering::Relaxed); })
+}
 
-    Timer::new(move || { h.fetch_add(1, Ordering::Relaxed); },
-               |_| Duration::from_millis(5))
-        .schedule_timeout();
+/*──────────── single‑shot ───────────*/
+
+#[cross_test]
+async fn fires_exactly_once() {
+    let (hit, cb) = counter();
+    MockTimer::new_with(cb, |_| 5 * MS).schedule_timeout();
 
-    wait(10).await;
+    MockRt::advance(4 * MS);
+    assert_eq!(hit.load(Ordering::Relaxed), 0);
+    MockRt::advance(MS);
+    assert_eq!(hit.load(Ordering::Relaxed), 1);
+    MockRt::advance(100 * MS);
     assert_eq!(hit.load(Ordering::Relaxed), 1);
 }
 
@@ -32,7 +43,7 @@ async fn fires_exactly_once() {
 
 #[cross_test]
 async fn reset_clears_counter() {
-    let t = Timer::new(|| {}, |_| Duration::ZERO);
+    let t = MockTimer::new_with(|| {}, |_| Duration::ZERO);
     t.schedule_timeout();
     t.reset();
     assert_eq!(t.tries(), 0);
@@ -42,17 +53,13 @@ async fn reset_clears_counter() {
 
 #[cross_test]
 async fn tries_and_hits_match_multiple_calls() {
-    let hit = Arc::new(AtomicUsize::new(0));
-    let t = {
-        let h = hit.clone();
-        Timer::new(move || { h.fetch_add(1, Ordering::Relaxed); },
-                   |_| Duration::from_millis(1))
-    };
+    let (hit, cb) = counter();
+    let t = MockTimer::new_with(cb, |_| MS);
 
     for _ in 0..3 { t.schedule_timeout(); }
 
     assert_eq!(t.tries(), 3);     // counter increments immediately
-    wait(10).await;
+    MockRt::advance(MS);
     assert_eq!(hit.load(Ordering::Relaxed), 3);
 }
 
@@ -60,12 +67,12 @@ async fn tries_and_hits_match_multiple_calls() {
 
 #[cross_test]
 async fn clone_shares_counter() {
-    let t1 = Timer::new(|| {}, |_| Duration::from_millis(1));
+    let t1 = MockTimer::new_with(|| {}, |_| MS);
     let t2 = t1.clone();
 
     t1.schedule_timeout();
     t2.schedule_timeout();
-    wait(5).await;
+    MockRt::advance(MS);
 
     assert_eq!(t1.tries(), 2);
     assert_eq!(t2.tries(), 2);
@@ -73,60 +80,69 @@ async fn clone_shares_counter() {
 
 /*──────────── base sleep helper ───────────*/
 
-#[cfg(not(all(feature = "browser", target_arch = "wasm32")))]
 #[cross_test]
 async fn base_sleep_waits_long_enough() {
-    let base = Duration::from_millis(4);
-    let t = Timer::new(|| {}, move |_| base);
-
-    let before = std::time::Instant::now();
-    t.sleep().await;
-    assert!(before.elapsed() >= base);
-}
+    let base = 4 * MS;
+    let t = MockTimer::new_with(|| {}, move |_| base);
 
-#[cfg(all(feature = "browser", target_arch = "wasm32"))]
-#[cross_test]
-async fn base_sleep_does_not_panic() {
-    let t = Timer::new(|| {}, |_| Duration::from_millis(4));
-    t.sleep().await;          // Instant::now() is unavailable in wasm32
+    let before = time::now_with::<MockRt>();
+    MockRt::block_on(t.sleep());
+    assert_eq!(time::now_with::<MockRt>() - before, base);
 }
 
 /*──────────── monotonic back‑off ───────────*/
 
-/*──────────── monotonic back-off ───────────*/
 #[cross_test]
 async fn backoff_is_monotonic() {
-    use std::sync::Mutex;
-    let stamps = Arc::new(Mutex::new(Vec::<u128>::new()));
+    let stamps = Arc::new(Mutex::new(Vec::new()));
+    let start = time::now_with::<MockRt>();
 
     let timer = {
         let s = stamps.clone();
-        Timer::new(move || s.lock().unwrap().push(epoch_ms()),
-                   |n| Duration::from_millis(2 * n as u64))
+        MockTimer::new_with(move || s.lock().unwrap().push(time::now_with::<MockRt>() - start),
+                            |n| 2 * n as u32 * MS)
     };
 
     for _ in 0..3 { timer.schedule_timeout(); }
-    wait(15).await;
+    MockRt::advance(15 * MS);
 
-    let v = stamps.lock().unwrap();
-    assert!(v[0] < v[1] && v[1] < v[2]);
+    assert_eq!(*stamps.lock().unwrap(), [2 * MS, 4 * MS, 6 * MS]);
 }
-use everywhere_runtime::time::{epoch_ms, now_ms};
 
-/*──────────── JoinHandle (native only) ───────────*/
+/*──────────── JoinHandle ───────────*/
 
-#[cfg(feature = "native")]
 #[cross_test]
 async fn joinhandle_completes_ok() {
-    let done = Arc::new(AtomicUsize::new(0));
-    let d = done.clone();
-
-    let jh = Timer::new(move || { d.store(1, Ordering::Relaxed); },
-                        |_| Duration::from_millis(1))
-        .schedule_timeout();
+    let (hit, cb) = counter();
+    let jh = Timer::new(cb, |_| MS).schedule_timeout();
 
     jh.await.unwrap();
-    assert_eq!(done.load(Ordering::Relaxed), 1);
+    assert_eq!(hit.load(Ordering::Relaxed), 1);
+}
+
+#[cross_test]
+async fn joinhandle_completes_on_mock() {
+    let (hit, cb) = counter();
+    let jh = MockTimer::new_with(cb, |_| Duration::from_secs(60)).schedule_timeout();
+
+    MockRt::block_on(jh).unwrap();
+    assert_eq!(hit.load(Ordering::Relaxed), 1);
+}
+
+/*──────────── TaskGroup ownership ───────────*/
+
+#[cross_test]
+async fn group_drop_cancels_pending_callback() {
+    let (hit, cb) = counter();
+    let t = MockTimer::new_with(cb, |_| 5 * MS);
+
+    let mut group = TaskGroup::new();
+    t.schedule_timeout_in(&mut group);
+    drop(group);
+
+    MockRt::advance(15 * MS);
+    assert_eq!(t.tries(), 1);
+    assert_eq!(hit.load(Ordering::Relaxed), 0);
 }
 
 /*──────────── Debug impl ───────────*/